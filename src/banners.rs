@@ -0,0 +1,385 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    Cause, Error, Language, NullSink, Operation, PathBufLikeReader, Result,
+    Sink, StderrSink, Step,
+};
+use std::path::{Path, PathBuf};
+
+/// The content of a boxed comment banner.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Banner {
+    text: String,
+    title: Option<String>,
+}
+
+impl Banner {
+    /// Create a new instance from the given title and text.
+    #[must_use]
+    pub fn new(title: Option<&str>, text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            title: title.map(ToString::to_string),
+        }
+    }
+
+    /// Parse the banner at the beginning of the given input.
+    ///
+    /// The input needs to begin with the top border of a box in the given
+    /// [`crate::BannerStyle`].  Anything after the bottom border is ignored.
+    /// The width of the box is not checked.  Leading and trailing empty lines
+    /// of the text are removed, just like the margins.
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    pub fn parse(input: &str, style: &BannerStyle) -> Result<Self> {
+        scan(input, style).map_err(|syntax| syntax.into_error(None))
+    }
+
+    /// Render this banner in the given [`crate::BannerStyle`].
+    ///
+    /// The title, if any, is centred in the top border.  The text is padded
+    /// by an empty line above and below as well as two spaces on either side.
+    /// Lines which are too long for the box are wrapped at spaces.  Each line
+    /// of the result ends with a newline character.
+    #[must_use]
+    pub fn render(&self, style: &BannerStyle) -> String {
+        let (prefix, open, close) = style.syntax();
+        let width = style.width.saturating_sub(prefix.chars().count());
+        let inner = width.saturating_sub(2);
+        let fill = |count: usize| style.fill.to_string().repeat(count);
+        let side = |content: &str| {
+            format!(
+                "{prefix}{border}{content}{}{border}",
+                " ".repeat(inner.saturating_sub(content.chars().count())),
+                border = style.border,
+            )
+        };
+        let title = self
+            .title
+            .as_ref()
+            .map_or_else(String::new, |title| format!(" {title} "));
+        let free = width.saturating_sub(title.chars().count());
+        let left = free / 2;
+
+        let mut lines = vec![
+            format!(
+                "{prefix}{open}{}{title}{}\\",
+                fill(left.saturating_sub(open.chars().count())),
+                fill((free - left).saturating_sub(1)),
+            ),
+            side(""),
+        ];
+
+        for line in self.text.lines() {
+            for part in wrap(line, inner.saturating_sub(4)) {
+                lines.push(side(&format!("  {part}")));
+            }
+        }
+
+        lines.push(side(""));
+        lines.push(format!(
+            "{prefix}\\{}{close}",
+            fill(width.saturating_sub(1 + close.chars().count())),
+        ));
+
+        lines
+            .iter()
+            .flat_map(|line| [line.as_str(), "\n"])
+            .collect()
+    }
+
+    /// The text of this banner.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The title shown in the top border, if any.
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
+
+/// Read the banner of files given as instances convertible to a
+/// [`std::path::PathBuf`].
+pub trait BannerReader {
+    /// Read the banner at the beginning of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::BannerReader::read_banner_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_banner_loudly(&self, style: &BannerStyle) -> Result<Banner>;
+
+    /// Read the banner at the beginning of the file this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and its beginning will be
+    /// parsed just like by [`crate::Banner::parse`].
+    ///
+    /// The return value is either the [`crate::Banner`], in case of success,
+    /// or a [`crate::Error`] to describe the error cause, otherwise.
+    /// Malformed banners are reported as [`crate::Cause::Parse`].
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_banner_silently(&self, style: &BannerStyle) -> Result<Banner>;
+
+    /// Read the banner at the beginning of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::BannerReader::read_banner_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_banner_with<S: Sink>(
+        &self,
+        style: &BannerStyle,
+        sink: S,
+    ) -> Result<Banner>;
+}
+
+impl<T> BannerReader for T
+where
+    PathBuf: From<T>,
+    T: Clone,
+{
+    fn read_banner_loudly(&self, style: &BannerStyle) -> Result<Banner> {
+        self.read_banner_with(style, StderrSink)
+    }
+
+    fn read_banner_silently(&self, style: &BannerStyle) -> Result<Banner> {
+        self.read_banner_with(style, NullSink)
+    }
+
+    fn read_banner_with<S: Sink>(
+        &self,
+        style: &BannerStyle,
+        mut sink: S,
+    ) -> Result<Banner> {
+        let path = PathBuf::from(self.clone());
+        let content = path.read_with(&mut sink)?;
+
+        scan(&content, style)
+            .map_err(|syntax| syntax.into_error(Some(&path)))
+            .inspect_err(|e| sink.report(e))
+    }
+}
+
+/// The shape of a boxed comment banner.
+///
+/// The default style is the one of this crate's license banners:  80 columns,
+/// filled with `*` and bordered by `|` in a Rust block comment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BannerStyle {
+    border: char,
+    fill: char,
+    language: Language,
+    width: usize,
+}
+
+impl BannerStyle {
+    /// Set the character of the left and right border.
+    #[must_use]
+    pub const fn border(mut self, border: char) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Set the character of the top and bottom border.
+    #[must_use]
+    pub const fn fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Set the comment syntax of the box.
+    ///
+    /// C and Rust boxes form a block comment, HTML boxes an HTML comment.
+    /// For [`crate::Language::Hash`], each line of the box is prefixed by
+    /// `# ` instead.
+    #[must_use]
+    pub const fn language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Create a new instance with the default settings.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            border: '|',
+            fill: '*',
+            language: Language::Rust,
+            width: 80,
+        }
+    }
+
+    /// Set the width of each line of the box, including the comment markers.
+    #[must_use]
+    pub const fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    const fn syntax(self) -> (&'static str, &'static str, &'static str) {
+        match self.language {
+            Language::C | Language::Rust => ("", "/*", "*/"),
+            Language::Hash => ("# ", "/", "/"),
+            Language::Html => ("", "<!--", "-->"),
+        }
+    }
+}
+
+impl Default for BannerStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Syntax(usize, usize, String);
+
+impl Syntax {
+    fn into_error(self, path: Option<&Path>) -> Error {
+        Error::new(
+            Operation::Read,
+            Step::Parse,
+            path.map(Path::to_path_buf),
+            Cause::Parse {
+                line: self.0,
+                column: self.1,
+                reason: self.2,
+            },
+        )
+    }
+}
+
+fn scan(
+    input: &str,
+    style: &BannerStyle,
+) -> std::result::Result<Banner, Syntax> {
+    let (prefix, open, close) = style.syntax();
+    let mut lines = input.lines().zip(1..);
+    let title = lines
+        .next()
+        .and_then(|(line, _)| {
+            line.trim_end()
+                .strip_prefix(prefix)?
+                .strip_prefix(open)?
+                .strip_suffix('\\')
+        })
+        .ok_or_else(|| {
+            Syntax(
+                1,
+                1,
+                format!("expected top border starting with '{prefix}{open}'"),
+            )
+        })?
+        .trim_matches(style.fill)
+        .trim();
+    let mut text = Vec::new();
+    let mut last = 1;
+
+    for (line, number) in lines {
+        let line = line.trim_end();
+        let Some(inner) = line.strip_prefix(prefix) else {
+            return Err(Syntax(number, 1, format!("expected '{prefix}'")));
+        };
+
+        if inner.starts_with('\\') && inner.ends_with(close) {
+            let begin = text
+                .iter()
+                .position(|line: &&str| !line.is_empty())
+                .unwrap_or(text.len());
+            let end = text
+                .iter()
+                .rposition(|line| !line.is_empty())
+                .map_or(begin, |i| i + 1);
+
+            return Ok(Banner {
+                text: text[begin..end]
+                    .iter()
+                    .flat_map(|line| [*line, "\n"])
+                    .collect(),
+                title: (!title.is_empty()).then(|| title.to_string()),
+            });
+        }
+
+        let content = inner
+            .strip_prefix(style.border)
+            .and_then(|inner| inner.strip_suffix(style.border))
+            .ok_or_else(|| {
+                Syntax(
+                    number,
+                    prefix.len() + 1,
+                    format!("expected side borders '{}'", style.border),
+                )
+            })?;
+
+        text.push(
+            content
+                .strip_prefix("  ")
+                .unwrap_or_else(|| content.trim_start())
+                .trim_end(),
+        );
+        last = number;
+    }
+
+    Err(Syntax(last + 1, 1, "expected bottom border".to_string()))
+}
+
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut rest = line.trim_end();
+
+    while rest.chars().count() > width {
+        let limit = rest
+            .char_indices()
+            .nth(width + 1)
+            .map_or(rest.len(), |(i, _)| i);
+        let Some(split) = rest[..limit]
+            .rfind(' ')
+            .filter(|&i| !rest[..i].trim().is_empty())
+            .or_else(|| rest[limit..].find(' ').map(|i| limit + i))
+        else {
+            break;
+        };
+
+        lines.push(rest[..split].trim_end().to_string());
+        rest = rest[split..].trim_start();
+    }
+
+    lines.push(rest.to_string());
+    lines
+}
+
+/******************************************************************************/
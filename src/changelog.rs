@@ -0,0 +1,552 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    ron::{self, Node, Syntax, Value},
+    Cause, Error, NullSink, Operation, PathBufLikeReader, Result, Sink,
+    StderrSink, Step, Version,
+};
+use std::path::{Path, PathBuf};
+
+/// A changelog in the RON format of the Aeruginous Open Source Development
+/// Toolbox.
+///
+/// The [`std::fmt::Display`] implementation renders the changelog just like
+/// the toolbox does:  indented by two spaces, with trailing commas and a final
+/// newline character.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Changelog {
+    introduction: Option<String>,
+    references: Vec<(String, String)>,
+    sections: Vec<Section>,
+}
+
+impl Changelog {
+    /// The introduction of this changelog, if any.
+    #[must_use]
+    pub fn introduction(&self) -> Option<&str> {
+        self.introduction.as_deref()
+    }
+
+    /// Create a new instance without any sections.
+    #[must_use]
+    pub const fn new(
+        introduction: Option<String>,
+        references: Vec<(String, String)>,
+    ) -> Self {
+        Self {
+            introduction,
+            references,
+            sections: Vec::new(),
+        }
+    }
+
+    /// Parse the given input.
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    pub fn parse(input: &str) -> Result<Self> {
+        ron::parse(input)
+            .and_then(|node| Self::from_node(&node))
+            .map_err(|syntax| syntax.into_error(None))
+    }
+
+    /// Insert the given section as the latest one.
+    pub fn prepend(&mut self, section: Section) {
+        self.sections.insert(0, section);
+    }
+
+    /// The references of this changelog.
+    #[must_use]
+    pub fn references(&self) -> &[(String, String)] {
+        &self.references
+    }
+
+    /// The sections of this changelog, the latest one first.
+    #[must_use]
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    fn from_node(node: &Node) -> std::result::Result<Self, Syntax> {
+        let [references, introduction, sections] =
+            node.fields(["references", "introduction", "sections"])?;
+
+        Ok(Self {
+            introduction: optional(introduction)?,
+            references: pairs(references)?,
+            sections: sections
+                .list()?
+                .iter()
+                .map(Section::from_node)
+                .collect::<std::result::Result<_, _>>()?,
+        })
+    }
+
+    fn node(&self) -> Node {
+        structure(vec![
+            ("references", references(&self.references)),
+            ("introduction", text_option(self.introduction.as_deref())),
+            (
+                "sections",
+                Node::new(Value::List(
+                    self.sections.iter().map(Section::node).collect(),
+                )),
+            ),
+        ])
+    }
+}
+
+impl std::fmt::Display for Changelog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.node().render(0))
+    }
+}
+
+/// Read changelogs and their fragments from files given as instances
+/// convertible to a [`std::path::PathBuf`].
+pub trait ChangelogReader {
+    /// Read the changelog this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::ChangelogReader::read_changelog_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_changelog_loudly(&self) -> Result<Changelog>;
+
+    /// Read the changelog this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and parsed just like by
+    /// [`crate::Changelog::parse`].
+    ///
+    /// The return value is either the [`crate::Changelog`], in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    /// Malformed changelogs are reported as [`crate::Cause::Parse`], pointing
+    /// to the offending line and column.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_changelog_silently(&self) -> Result<Changelog>;
+
+    /// Read the changelog this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::ChangelogReader::read_changelog_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_changelog_with<S: Sink>(&self, sink: S) -> Result<Changelog>;
+
+    /// Read the changelog fragment this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::ChangelogReader::read_fragment_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_fragment_loudly(&self) -> Result<Fragment>;
+
+    /// Read the changelog fragment this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::ChangelogReader::read_changelog_silently`] but parses the file
+    /// just like [`crate::Fragment::parse`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_fragment_silently(&self) -> Result<Fragment>;
+
+    /// Read the changelog fragment this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::ChangelogReader::read_fragment_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_fragment_with<S: Sink>(&self, sink: S) -> Result<Fragment>;
+}
+
+impl<T> ChangelogReader for T
+where
+    PathBuf: From<T>,
+    T: Clone,
+{
+    fn read_changelog_loudly(&self) -> Result<Changelog> {
+        self.read_changelog_with(StderrSink)
+    }
+
+    fn read_changelog_silently(&self) -> Result<Changelog> {
+        self.read_changelog_with(NullSink)
+    }
+
+    fn read_changelog_with<S: Sink>(&self, mut sink: S) -> Result<Changelog> {
+        let path = PathBuf::from(self.clone());
+        let content = path.read_with(&mut sink)?;
+
+        ron::parse(&content)
+            .and_then(|node| Changelog::from_node(&node))
+            .map_err(|syntax| syntax.into_error(Some(&path)))
+            .inspect_err(|e| sink.report(e))
+    }
+
+    fn read_fragment_loudly(&self) -> Result<Fragment> {
+        self.read_fragment_with(StderrSink)
+    }
+
+    fn read_fragment_silently(&self) -> Result<Fragment> {
+        self.read_fragment_with(NullSink)
+    }
+
+    fn read_fragment_with<S: Sink>(&self, mut sink: S) -> Result<Fragment> {
+        let path = PathBuf::from(self.clone());
+        let content = path.read_with(&mut sink)?;
+
+        ron::parse(&content)
+            .and_then(|node| Fragment::from_node(&node))
+            .map_err(|syntax| syntax.into_error(Some(&path)))
+            .inspect_err(|e| sink.report(e))
+    }
+}
+
+/// The categorised changes of a changelog section or fragment.
+///
+/// The [`std::fmt::Display`] implementation renders the fragment in the same
+/// style as [`crate::Changelog`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Fragment {
+    changes: Vec<(String, Vec<String>)>,
+    references: Vec<(String, String)>,
+}
+
+impl Fragment {
+    /// Add a change to the given category.
+    pub fn add(&mut self, category: &str, change: &str) {
+        if let Some((_, changes)) =
+            self.changes.iter_mut().find(|(name, _)| name == category)
+        {
+            changes.push(change.to_string());
+        } else {
+            self.changes
+                .push((category.to_string(), vec![change.to_string()]));
+        }
+    }
+
+    /// The changes of this fragment by category.
+    #[must_use]
+    pub fn changes(&self) -> &[(String, Vec<String>)] {
+        &self.changes
+    }
+
+    /// Merge the changes and references of the given fragment into this one.
+    ///
+    /// Afterwards, the categories, their changes and the references are
+    /// sorted.  Duplicate references are only kept once.
+    ///
+    /// # Errors
+    ///
+    /// If both fragments assign different URLs to the same reference, the
+    /// conflict is reported as [`std::io::ErrorKind::InvalidData`] and this
+    /// instance is left unchanged.
+    pub fn merge(&mut self, other: Self) -> Result<()> {
+        self.merge_from(other, None)
+    }
+
+    pub(crate) fn merge_from(
+        &mut self,
+        other: Self,
+        path: Option<&Path>,
+    ) -> Result<()> {
+        let mut references = self.references.clone();
+
+        references.extend(other.references);
+        references.sort();
+        references.dedup();
+
+        if let Some(pair) =
+            references.windows(2).find(|pair| pair[0].0 == pair[1].0)
+        {
+            return Err(Error::new(
+                Operation::Read,
+                Step::Parse,
+                path.map(Path::to_path_buf),
+                Cause::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "conflicting URLs '{}' and '{}' for reference '{}'",
+                        pair[0].1, pair[1].1, pair[0].0
+                    ),
+                )),
+            ));
+        }
+
+        for (category, changes) in other.changes {
+            for change in changes {
+                self.add(&category, &change);
+            }
+        }
+
+        self.references = references;
+        self.changes.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (_, changes) in &mut self.changes {
+            changes.sort();
+        }
+
+        Ok(())
+    }
+
+    /// Create a new and empty instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            changes: Vec::new(),
+            references: Vec::new(),
+        }
+    }
+
+    /// Parse the given input.
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    pub fn parse(input: &str) -> Result<Self> {
+        ron::parse(input)
+            .and_then(|node| Self::from_node(&node))
+            .map_err(|syntax| syntax.into_error(None))
+    }
+
+    /// The references of this fragment.
+    #[must_use]
+    pub fn references(&self) -> &[(String, String)] {
+        &self.references
+    }
+
+    fn from_node(node: &Node) -> std::result::Result<Self, Syntax> {
+        let [references, changes] = node.fields(["references", "changes"])?;
+
+        Ok(Self {
+            changes: changes
+                .map()?
+                .iter()
+                .map(|(category, changes)| {
+                    Ok((
+                        category.string()?.to_string(),
+                        changes
+                            .list()?
+                            .iter()
+                            .map(|change| Ok(change.string()?.to_string()))
+                            .collect::<std::result::Result<_, _>>()?,
+                    ))
+                })
+                .collect::<std::result::Result<_, _>>()?,
+            references: pairs(references)?,
+        })
+    }
+
+    fn node(&self) -> Node {
+        structure(vec![
+            ("references", references(&self.references)),
+            (
+                "changes",
+                Node::new(Value::Map(
+                    self.changes
+                        .iter()
+                        .map(|(category, changes)| {
+                            (
+                                text(category),
+                                Node::new(Value::List(
+                                    changes
+                                        .iter()
+                                        .map(|change| text(change))
+                                        .collect(),
+                                )),
+                            )
+                        })
+                        .collect(),
+                )),
+            ),
+        ])
+    }
+}
+
+impl std::fmt::Display for Fragment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.node().render(0))
+    }
+}
+
+/// A section of a [`crate::Changelog`], describing one release.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Section {
+    changes: Fragment,
+    introduction: Option<String>,
+    references: Vec<(String, String)>,
+    released: String,
+    version: Version,
+}
+
+impl Section {
+    /// The changes of this release.
+    #[must_use]
+    pub const fn changes(&self) -> &Fragment {
+        &self.changes
+    }
+
+    /// The introduction of this section, if any.
+    #[must_use]
+    pub fn introduction(&self) -> Option<&str> {
+        self.introduction.as_deref()
+    }
+
+    /// Create a new instance without introduction and references.
+    ///
+    /// The release date is expected to be an RFC 3339 timestamp, such as
+    /// `2024-06-06T11:49:35.668876508Z`.
+    #[must_use]
+    pub fn new(version: Version, released: &str, changes: Fragment) -> Self {
+        Self {
+            changes,
+            introduction: None,
+            references: Vec::new(),
+            released: released.to_string(),
+            version,
+        }
+    }
+
+    /// The references of this section.
+    #[must_use]
+    pub fn references(&self) -> &[(String, String)] {
+        &self.references
+    }
+
+    /// The release date of this section.
+    #[must_use]
+    pub fn released(&self) -> &str {
+        &self.released
+    }
+
+    /// The version of this release.
+    #[must_use]
+    pub const fn version(&self) -> Version {
+        self.version
+    }
+
+    fn from_node(node: &Node) -> std::result::Result<Self, Syntax> {
+        let [references, version, released, introduction, changes] = node
+            .fields([
+                "references",
+                "version",
+                "released",
+                "introduction",
+                "changes",
+            ])?;
+        let [major, minor, patch] =
+            version.fields(["major", "minor", "patch"])?;
+
+        Ok(Self {
+            changes: Fragment::from_node(changes)?,
+            introduction: optional(introduction)?,
+            references: pairs(references)?,
+            released: released.string()?.to_string(),
+            version: Version::new(
+                major.integer()?,
+                minor.integer()?,
+                patch.integer()?,
+            ),
+        })
+    }
+
+    fn node(&self) -> Node {
+        let integer = |integer| Node::new(Value::Integer(integer));
+
+        structure(vec![
+            ("references", references(&self.references)),
+            (
+                "version",
+                structure(vec![
+                    ("major", integer(self.version.major())),
+                    ("minor", integer(self.version.minor())),
+                    ("patch", integer(self.version.patch())),
+                ]),
+            ),
+            ("released", text(&self.released)),
+            ("introduction", text_option(self.introduction.as_deref())),
+            ("changes", self.changes.node()),
+        ])
+    }
+}
+
+fn optional(node: &Node) -> std::result::Result<Option<String>, Syntax> {
+    node.option()?
+        .map(|node| Ok(node.string()?.to_string()))
+        .transpose()
+}
+
+fn pairs(node: &Node) -> std::result::Result<Vec<(String, String)>, Syntax> {
+    node.map()?
+        .iter()
+        .map(|(key, value)| {
+            Ok((key.string()?.to_string(), value.string()?.to_string()))
+        })
+        .collect()
+}
+
+fn references(references: &[(String, String)]) -> Node {
+    Node::new(Value::Map(
+        references
+            .iter()
+            .map(|(key, value)| (text(key), text(value)))
+            .collect(),
+    ))
+}
+
+fn structure(fields: Vec<(&str, Node)>) -> Node {
+    Node::new(Value::Struct(
+        fields
+            .into_iter()
+            .map(|(name, node)| (name.to_string(), node))
+            .collect(),
+    ))
+}
+
+fn text(text: &str) -> Node {
+    Node::new(Value::String(text.to_string()))
+}
+
+fn text_option(option: Option<&str>) -> Node {
+    Node::new(Value::Option(option.map(|string| Box::new(text(string)))))
+}
+
+/******************************************************************************/
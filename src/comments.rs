@@ -0,0 +1,497 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{NullSink, PathBufLikeReader, Result, Sink, StderrSink};
+use std::path::{Path, PathBuf};
+
+/// A comment block.
+///
+/// Consecutive line comments which occupy lines of their own are merged into
+/// one block.  Any other comment forms a block of its own.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Comment {
+    begin: usize,
+    end: usize,
+    text: String,
+}
+
+impl Comment {
+    /// The first line of this block, starting at one.
+    #[must_use]
+    pub const fn begin(&self) -> usize {
+        self.begin
+    }
+
+    /// The last line of this block, starting at one.
+    #[must_use]
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// The text of this block, including the comment markers.
+    ///
+    /// Merged line comments are separated by newline characters.  The
+    /// indentation of the first line is not part of the text.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Read the comments or the code of files given as instances convertible to
+/// a [`std::path::PathBuf`].
+pub trait CommentReader {
+    /// Read the code of the file this method is called on.
+    ///
+    /// This method behaves just like [`crate::CommentReader::code_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn code_loudly(&self, language: Language) -> Result<String>;
+
+    /// Read the code of the file this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and its comments will be
+    /// removed according to the given [`crate::Language`].  Lines which only
+    /// consisted of comments and whitespace will be removed entirely, the
+    /// trailing whitespace of the remaining lines with comments will be
+    /// trimmed.
+    ///
+    /// The return value is either the code as a [`String`], in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn code_silently(&self, language: Language) -> Result<String>;
+
+    /// Read the code of the file this method is called on.
+    ///
+    /// This method behaves just like [`crate::CommentReader::code_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn code_with<S: Sink>(&self, language: Language, sink: S)
+        -> Result<String>;
+
+    /// Read the comment blocks of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::CommentReader::comment_blocks_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn comment_blocks_loudly(&self, language: Language)
+        -> Result<Vec<Comment>>;
+
+    /// Read the comment blocks of the file this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and searched for comments
+    /// according to the given [`crate::Language`].
+    ///
+    /// The return value is either the list of [`crate::Comment`] blocks, in
+    /// the order of their appearance, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn comment_blocks_silently(
+        &self,
+        language: Language,
+    ) -> Result<Vec<Comment>>;
+
+    /// Read the comment blocks of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::CommentReader::comment_blocks_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn comment_blocks_with<S: Sink>(
+        &self,
+        language: Language,
+        sink: S,
+    ) -> Result<Vec<Comment>>;
+
+    /// Read the comments of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::CommentReader::comments_silently`] despite also printing error
+    /// messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn comments_loudly(&self, language: Language) -> Result<String>;
+
+    /// Read the comments of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::CommentReader::comment_blocks_silently`] but joins the texts
+    /// of all blocks, each followed by a newline character.
+    ///
+    /// The return value is either the comments as a [`String`], in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn comments_silently(&self, language: Language) -> Result<String>;
+
+    /// Read the comments of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::CommentReader::comments_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn comments_with<S: Sink>(
+        &self,
+        language: Language,
+        sink: S,
+    ) -> Result<String>;
+}
+
+impl<T> CommentReader for T
+where
+    PathBuf: From<T>,
+    T: Clone,
+{
+    fn code_loudly(&self, language: Language) -> Result<String> {
+        self.code_with(language, StderrSink)
+    }
+
+    fn code_silently(&self, language: Language) -> Result<String> {
+        self.code_with(language, NullSink)
+    }
+
+    fn code_with<S: Sink>(
+        &self,
+        language: Language,
+        sink: S,
+    ) -> Result<String> {
+        Ok(language.code(&PathBuf::from(self.clone()).read_with(sink)?))
+    }
+
+    fn comment_blocks_loudly(
+        &self,
+        language: Language,
+    ) -> Result<Vec<Comment>> {
+        self.comment_blocks_with(language, StderrSink)
+    }
+
+    fn comment_blocks_silently(
+        &self,
+        language: Language,
+    ) -> Result<Vec<Comment>> {
+        self.comment_blocks_with(language, NullSink)
+    }
+
+    fn comment_blocks_with<S: Sink>(
+        &self,
+        language: Language,
+        sink: S,
+    ) -> Result<Vec<Comment>> {
+        Ok(language.blocks(&PathBuf::from(self.clone()).read_with(sink)?))
+    }
+
+    fn comments_loudly(&self, language: Language) -> Result<String> {
+        self.comments_with(language, StderrSink)
+    }
+
+    fn comments_silently(&self, language: Language) -> Result<String> {
+        self.comments_with(language, NullSink)
+    }
+
+    fn comments_with<S: Sink>(
+        &self,
+        language: Language,
+        sink: S,
+    ) -> Result<String> {
+        Ok(self
+            .comment_blocks_with(language, sink)?
+            .iter()
+            .flat_map(|block| [block.text(), "\n"])
+            .collect())
+    }
+}
+
+/// The comment syntaxes this crate knows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Language {
+    /// `//` and `/* */`, as used by C, C++ and Java, for instance.
+    C,
+
+    /// `#`, as used by shell scripts, Python, TOML and YAML, for instance.
+    ///
+    /// The hash sign only starts a comment at the beginning of a line or after
+    /// whitespace.
+    Hash,
+
+    /// `<!-- -->`, as used by HTML, Markdown and XML, for instance.
+    Html,
+
+    /// `//` and nestable `/* */`, including the documentation comments `///`,
+    /// `//!`, `/** */` and `/*! */`.
+    Rust,
+}
+
+impl Language {
+    /// Guess the language of the given file by its extension.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "c" | "cc" | "cpp" | "h" | "hpp" | "java" | "js" | "ts" => {
+                Some(Self::C)
+            }
+            "bash" | "cff" | "py" | "sh" | "toml" | "yaml" | "yml" => {
+                Some(Self::Hash)
+            }
+            "htm" | "html" | "md" | "xml" => Some(Self::Html),
+            "rs" => Some(Self::Rust),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn blocks(self, content: &str) -> Vec<Comment> {
+        let starts: Vec<usize> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let line = |offset: usize| starts.partition_point(|&s| s <= offset);
+        let mut blocks: Vec<Comment> = Vec::new();
+        let mut mergeable = false;
+
+        for (begin, end, kind) in self.scan(content) {
+            let text = &content[begin..end];
+            let first = line(begin);
+            let alone = content[starts[first - 1]..begin].trim().is_empty();
+
+            match blocks.last_mut() {
+                Some(block)
+                    if mergeable
+                        && alone
+                        && kind == Kind::Line
+                        && block.end + 1 == first =>
+                {
+                    block.text.push('\n');
+                    block.text.push_str(text);
+                    block.end = first;
+                }
+                _ => blocks.push(Comment {
+                    begin: first,
+                    end: line(end.saturating_sub(1).max(begin)),
+                    text: text.to_string(),
+                }),
+            }
+
+            mergeable = alone && kind == Kind::Line;
+        }
+
+        blocks
+    }
+
+    fn code(self, content: &str) -> String {
+        let mut code = String::with_capacity(content.len());
+        let mut commented = vec![false; content.lines().count() + 1];
+        let mut offset = 0;
+
+        for (begin, end, _) in self.scan(content) {
+            code.push_str(&content[offset..begin]);
+
+            let line = content[..begin].matches('\n').count();
+            let lines = content[begin..end].matches('\n').count();
+
+            for flag in &mut commented[line..=line + lines] {
+                *flag = true;
+            }
+
+            code.extend(content[begin..end].matches('\n'));
+            offset = end;
+        }
+
+        code.push_str(&content[offset..]);
+
+        code.split_inclusive('\n')
+            .zip(commented)
+            .filter_map(|(line, commented)| {
+                if !commented {
+                    Some(line.to_string())
+                } else if line.trim().is_empty() {
+                    None
+                } else {
+                    let ending = if line.ends_with('\n') { "\n" } else { "" };
+                    Some(line.trim_end().to_string() + ending)
+                }
+            })
+            .collect()
+    }
+
+    fn scan(self, content: &str) -> Vec<(usize, usize, Kind)> {
+        let bytes = content.as_bytes();
+        let mut comments = Vec::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let rest = &content[i..];
+            let after_space = i == 0 || bytes[i - 1].is_ascii_whitespace();
+            let line_comment = match self {
+                Self::C | Self::Rust => rest.starts_with("//"),
+                Self::Hash => bytes[i] == b'#' && after_space,
+                Self::Html => false,
+            };
+
+            if line_comment {
+                let end = rest.find('\n').map_or(bytes.len(), |n| i + n);
+                comments.push((i, end.max(i), Kind::Line));
+                i = end;
+            } else if self == Self::Html && rest.starts_with("<!--") {
+                let end =
+                    rest[4..].find("-->").map_or(bytes.len(), |n| i + n + 7);
+                comments.push((i, end, Kind::Block));
+                i = end;
+            } else if matches!(self, Self::C | Self::Rust)
+                && rest.starts_with("/*")
+            {
+                let end = self.block_end(content, i);
+                comments.push((i, end, Kind::Block));
+                i = end;
+            } else if let Some(end) = self.literal(content, i) {
+                i = end;
+            } else {
+                i += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+
+        comments
+    }
+
+    fn block_end(self, content: &str, begin: usize) -> usize {
+        let bytes = content.as_bytes();
+        let mut depth = 0;
+        let mut i = begin;
+
+        while i + 1 < bytes.len() {
+            match (bytes[i], bytes[i + 1]) {
+                (b'/', b'*') if depth == 0 || self == Self::Rust => {
+                    depth += 1;
+                    i += 2;
+                }
+                (b'*', b'/') => {
+                    depth -= 1;
+                    i += 2;
+
+                    if depth == 0 {
+                        return i;
+                    }
+                }
+                _ => i += 1,
+            }
+        }
+
+        bytes.len()
+    }
+
+    fn literal(self, content: &str, begin: usize) -> Option<usize> {
+        let bytes = content.as_bytes();
+        let previous = begin.checked_sub(1).map(|i| bytes[i]);
+        let word =
+            previous.is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_');
+
+        match (self, bytes[begin]) {
+            (Self::C | Self::Rust, b'"') => {
+                Some(string(content, begin, b'"', true))
+            }
+            (Self::Rust, b'r') if !word => {
+                let hashes = bytes[begin + 1..]
+                    .iter()
+                    .take_while(|&&b| b == b'#')
+                    .count();
+
+                (bytes.get(begin + 1 + hashes) == Some(&b'"')).then(|| {
+                    let closing = "\"".to_string() + &"#".repeat(hashes);
+
+                    content[begin + hashes + 2..]
+                        .find(&closing)
+                        .map_or(bytes.len(), |n| {
+                            begin + hashes + 2 + n + closing.len()
+                        })
+                })
+            }
+            (Self::C | Self::Rust, b'\'') => match bytes.get(begin + 1) {
+                Some(b'\\') => Some(string(content, begin, b'\'', false)),
+                Some(_) => {
+                    let next = content[begin + 1..].chars().next()?;
+                    let end = begin + 1 + next.len_utf8();
+
+                    (bytes.get(end) == Some(&b'\'')).then_some(end + 1)
+                }
+                None => None,
+            },
+            (Self::Hash, b'"' | b'\'') if !word => {
+                Some(string(content, begin, bytes[begin], false))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Block,
+    Line,
+}
+
+fn string(content: &str, begin: usize, quote: u8, multiline: bool) -> usize {
+    let bytes = content.as_bytes();
+    let mut i = begin + 1;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'\n' if !multiline => return i,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/******************************************************************************/
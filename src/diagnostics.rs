@@ -0,0 +1,455 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{Error, Operation};
+use std::{
+    fmt::Write as _,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Collect diagnostic messages in memory.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BufferSink {
+    messages: Vec<String>,
+}
+
+impl BufferSink {
+    /// Extract the collected messages.
+    #[must_use]
+    pub fn into_messages(self) -> Vec<String> {
+        self.messages
+    }
+
+    /// The messages collected so far.
+    #[must_use]
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// Create a new instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+        }
+    }
+}
+
+impl Sink for BufferSink {
+    fn report(&mut self, error: &Error) {
+        self.messages.push(error.to_string());
+    }
+}
+
+/// A successful operation.
+///
+/// Events are passed to [`crate::Sink::record`] in order to trace what the
+/// operations of this crate did.  Operations which succeeded despite a
+/// recoverable problem, such as retried writes, are recorded as warnings.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    bytes: usize,
+    operation: Operation,
+    path: Option<PathBuf>,
+    warning: Option<String>,
+}
+
+impl Event {
+    /// The number of bytes which were processed.
+    #[must_use]
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// The [`crate::Level`] this event should be logged with.
+    ///
+    /// Warnings are logged with [`crate::Level::Warn`], completed writes with
+    /// [`crate::Level::Info`] and any other events with
+    /// [`crate::Level::Debug`].
+    #[must_use]
+    pub const fn level(&self) -> Level {
+        match (&self.warning, self.operation) {
+            (Some(_), _) => Level::Warn,
+            (None, Operation::Read) => Level::Debug,
+            (None, _) => Level::Info,
+        }
+    }
+
+    /// The description of this event without the affected file.
+    #[must_use]
+    pub fn message(&self) -> String {
+        let n = self.bytes;
+
+        if let Some(warning) = &self.warning {
+            return warning.clone();
+        }
+
+        match self.operation {
+            Operation::Append => format!("appended {n} bytes"),
+            Operation::Patch => format!("patched {n} bytes"),
+            Operation::Read => format!("read {n} bytes"),
+            Operation::Remove => "removed".to_string(),
+            Operation::Truncate => format!("truncated to {n} bytes"),
+            Operation::Write => format!("wrote {n} bytes"),
+        }
+    }
+
+    /// Create a new instance.
+    #[must_use]
+    pub const fn new(
+        operation: Operation,
+        path: Option<PathBuf>,
+        bytes: usize,
+    ) -> Self {
+        Self {
+            bytes,
+            operation,
+            path,
+            warning: None,
+        }
+    }
+
+    /// The operation which succeeded.
+    #[must_use]
+    pub const fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// The file which was affected, if any.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Create a new instance describing a recoverable problem.
+    #[must_use]
+    pub const fn warn(
+        operation: Operation,
+        path: Option<PathBuf>,
+        warning: String,
+    ) -> Self {
+        Self {
+            bytes: 0,
+            operation,
+            path,
+            warning: Some(warning),
+        }
+    }
+
+    /// The description of the recoverable problem, if any.
+    #[must_use]
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n = self.bytes;
+        let target = self.path.as_ref().map_or_else(
+            || "the stream".to_string(),
+            |p| format!("'{}'", p.display()),
+        );
+
+        if let Some(warning) = &self.warning {
+            return write!(f, "Warning for {target}: {warning}.");
+        }
+
+        match self.operation {
+            Operation::Append => write!(f, "Appended {n} bytes to {target}."),
+            Operation::Patch => write!(f, "Patched {n} bytes of {target}."),
+            Operation::Read => write!(f, "Read {n} bytes from {target}."),
+            Operation::Remove => write!(f, "Removed {target}."),
+            Operation::Truncate => {
+                write!(f, "Truncated {target} to {n} bytes.")
+            }
+            Operation::Write => write!(f, "Wrote {n} bytes to {target}."),
+        }
+    }
+}
+
+/// The formats a [`crate::Logger`] can write messages in.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// `tool: path: message`, as recommended by the GNU Coding Standards.
+    ///
+    /// The path is omitted for streams.
+    Gnu {
+        /// The name of the tool to prefix each message with.
+        tool: String,
+    },
+
+    /// One JSON object per line.
+    ///
+    /// Each object has the string fields `level`, `operation` and `message`
+    /// as well as the field `path`, which is either a string or `null`.
+    JsonLines,
+
+    /// Human-readable sentences.
+    #[default]
+    Plain,
+}
+
+impl Format {
+    fn render(
+        &self,
+        level: Level,
+        operation: Operation,
+        path: Option<&Path>,
+        sentence: String,
+        message: &str,
+    ) -> String {
+        match self {
+            Self::Gnu { tool } => path.map_or_else(
+                || format!("{tool}: {message}"),
+                |p| format!("{tool}: {}: {message}", p.display()),
+            ),
+            Self::JsonLines => format!(
+                "{{\"level\":{},\"operation\":{},\"path\":{},\"message\":{}}}",
+                json(&level.to_string()),
+                json(match operation {
+                    Operation::Append => "append",
+                    Operation::Patch => "patch",
+                    Operation::Read => "read",
+                    Operation::Remove => "remove",
+                    Operation::Truncate => "truncate",
+                    Operation::Write => "write",
+                }),
+                path.map_or_else(
+                    || "null".to_string(),
+                    |p| json(&p.display().to_string())
+                ),
+                json(message)
+            ),
+            Self::Plain => sentence,
+        }
+    }
+}
+
+/// The verbosity levels of a [`crate::Logger`].
+///
+/// The levels are ordered by their verbosity, starting with the least verbose
+/// one.  A logger emits all messages whose level is less than or equal to its
+/// own one.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    /// Do not emit any messages.
+    Quiet,
+
+    /// Emit error messages.
+    #[default]
+    Error,
+
+    /// Emit warnings and error messages.
+    Warn,
+
+    /// Emit messages on completed writes, warnings and error messages.
+    Info,
+
+    /// Emit all messages, including the ones on successful reads.
+    Debug,
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Quiet => "quiet",
+                Self::Error => "error",
+                Self::Warn => "warning",
+                Self::Info => "info",
+                Self::Debug => "debug",
+            }
+        )
+    }
+}
+
+/// Write messages up to a certain [`crate::Level`] in a certain
+/// [`crate::Format`] to a [`std::io::Write`]r.
+///
+/// Each message will be terminated by a newline character.  Failures to write
+/// a message are ignored as there is no further destination to report them to.
+#[derive(Debug)]
+pub struct Logger<W: Write> {
+    destination: W,
+    format: Format,
+    level: Level,
+}
+
+impl<W: Write> Logger<W> {
+    /// Extract the underlying [`std::io::Write`]r.
+    pub fn into_inner(self) -> W {
+        self.destination
+    }
+
+    /// Create a new instance.
+    pub const fn new(destination: W, level: Level, format: Format) -> Self {
+        Self {
+            destination,
+            format,
+            level,
+        }
+    }
+
+    fn enabled(&self, level: Level) -> bool {
+        level != Level::Quiet && level <= self.level
+    }
+
+    fn emit(
+        &mut self,
+        level: Level,
+        operation: Operation,
+        path: Option<&Path>,
+        sentence: String,
+        message: &str,
+    ) {
+        let _ = writeln!(
+            self.destination,
+            "{}",
+            self.format
+                .render(level, operation, path, sentence, message)
+        );
+    }
+}
+
+impl<W: Write> Sink for Logger<W> {
+    fn record(&mut self, event: &Event) {
+        if self.enabled(event.level()) {
+            self.emit(
+                event.level(),
+                event.operation(),
+                event.path(),
+                event.to_string(),
+                &event.message(),
+            );
+        }
+    }
+
+    fn report(&mut self, error: &Error) {
+        if self.enabled(Level::Error) {
+            self.emit(
+                Level::Error,
+                error.operation(),
+                error.path(),
+                error.to_string(),
+                &error.message(),
+            );
+        }
+    }
+}
+
+/// Discard any diagnostic messages.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct NullSink;
+
+impl Sink for NullSink {
+    fn report(&mut self, _: &Error) {}
+}
+
+/// A destination for diagnostic messages.
+///
+/// All reading and writing traits of this crate offer methods which accept an
+/// instance of this trait in order to report any errors.  The methods ending
+/// on `_loudly` use a [`crate::StderrSink`] for this purpose, the methods
+/// ending on `_silently` use a [`crate::NullSink`].
+pub trait Sink {
+    /// Record the given successful operation.
+    ///
+    /// Events are ignored by default.
+    fn record(&mut self, event: &Event) {
+        let _ = event;
+    }
+
+    /// Report the given error.
+    fn report(&mut self, error: &Error);
+}
+
+impl<S: Sink + ?Sized> Sink for &mut S {
+    fn record(&mut self, event: &Event) {
+        (**self).record(event);
+    }
+
+    fn report(&mut self, error: &Error) {
+        (**self).report(error);
+    }
+}
+
+/// Write diagnostic messages to [`std::io::Stderr`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StderrSink;
+
+impl Sink for StderrSink {
+    fn report(&mut self, error: &Error) {
+        eprintln!("{error}");
+    }
+}
+
+/// Write diagnostic messages to a [`std::io::Write`]r.
+///
+/// Each message will be terminated by a newline character.  Failures to write
+/// a message are ignored as there is no further destination to report them to.
+#[derive(Debug, Default)]
+pub struct WriteSink<W: Write> {
+    destination: W,
+}
+
+impl<W: Write> WriteSink<W> {
+    /// Extract the underlying [`std::io::Write`]r.
+    pub fn into_inner(self) -> W {
+        self.destination
+    }
+
+    /// Create a new instance.
+    pub const fn new(destination: W) -> Self {
+        Self { destination }
+    }
+}
+
+impl<W: Write> Sink for WriteSink<W> {
+    fn report(&mut self, error: &Error) {
+        let _ = writeln!(self.destination, "{error}");
+    }
+}
+
+fn json(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(result, "\\u{:04x}", u32::from(c));
+            }
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+/******************************************************************************/
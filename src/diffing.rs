@@ -0,0 +1,358 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use std::{fmt::Write as _, path::Path};
+
+/// The settings for rendering unified diffs.
+///
+/// Instances are passed to the dry-run methods of this crate's writing traits
+/// in order to describe the changes they would apply.  By default, three lines
+/// of context are shown and the paths are labelled with `a/` and `b/`, just as
+/// `git diff` does.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Diff {
+    context: usize,
+    prefixes: bool,
+}
+
+impl Diff {
+    /// Set the number of unchanged lines to show around each change.
+    #[must_use]
+    pub const fn context(mut self, lines: usize) -> Self {
+        self.context = lines;
+        self
+    }
+
+    /// Create a new instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            context: 3,
+            prefixes: true,
+        }
+    }
+
+    /// Set whether to label the paths with `a/` and `b/`.
+    #[must_use]
+    pub const fn prefixes(mut self, prefixes: bool) -> Self {
+        self.prefixes = prefixes;
+        self
+    }
+
+    /// Render the unified diff between two versions of the given file.
+    ///
+    /// The original content is [`None`] if the file does not exist, yet.  The
+    /// lines are compared including their line endings.  A missing final
+    /// newline is marked by `\ No newline at end of file`.  If both versions
+    /// are equal, the diff is empty.
+    #[must_use]
+    pub fn unified(
+        &self,
+        path: &Path,
+        original: Option<&str>,
+        modified: &str,
+    ) -> String {
+        let old = lines(original.unwrap_or_default());
+        let new = lines(modified);
+        let edits = myers(&old, &new);
+        let mut result = String::new();
+
+        if edits.iter().all(|e| *e == Edit::Equal) {
+            return result;
+        }
+
+        let (a, b) = if self.prefixes {
+            ("a/", "b/")
+        } else {
+            ("", "")
+        };
+
+        if original.is_some() {
+            let _ = writeln!(result, "--- {a}{}", path.display());
+        } else {
+            result.push_str("--- /dev/null\n");
+        }
+
+        let _ = writeln!(result, "+++ {b}{}", path.display());
+
+        let mut included = vec![false; edits.len()];
+
+        for (i, _) in
+            edits.iter().enumerate().filter(|(_, e)| **e != Edit::Equal)
+        {
+            let end = (i + self.context + 1).min(edits.len());
+
+            for flag in &mut included[i.saturating_sub(self.context)..end] {
+                *flag = true;
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        let mut hunk = Hunk::default();
+
+        for (edit, included) in edits.iter().zip(included) {
+            if included {
+                if hunk.lines.is_empty() {
+                    hunk.old_start = i;
+                    hunk.new_start = j;
+                }
+
+                match edit {
+                    Edit::Delete => hunk.remove(old[i]),
+                    Edit::Equal => hunk.keep(old[i]),
+                    Edit::Insert => hunk.insert(new[j]),
+                }
+            } else if !hunk.lines.is_empty() {
+                std::mem::take(&mut hunk).render(&mut result);
+            }
+
+            match edit {
+                Edit::Delete => i += 1,
+                Edit::Equal => {
+                    i += 1;
+                    j += 1;
+                }
+                Edit::Insert => j += 1,
+            }
+        }
+
+        if !hunk.lines.is_empty() {
+            hunk.render(&mut result);
+        }
+
+        result
+    }
+}
+
+impl Default for Diff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Edit {
+    Delete,
+    Equal,
+    Insert,
+}
+
+#[derive(Default)]
+struct Hunk {
+    lines: String,
+    new_count: usize,
+    new_start: usize,
+    old_count: usize,
+    old_start: usize,
+}
+
+impl Hunk {
+    fn insert(&mut self, line: &str) {
+        self.line('+', line);
+        self.new_count += 1;
+    }
+
+    fn keep(&mut self, line: &str) {
+        self.line(' ', line);
+        self.new_count += 1;
+        self.old_count += 1;
+    }
+
+    fn line(&mut self, marker: char, line: &str) {
+        self.lines.push(marker);
+        self.lines.push_str(line);
+
+        if !line.ends_with('\n') {
+            self.lines.push_str("\n\\ No newline at end of file\n");
+        }
+    }
+
+    fn remove(&mut self, line: &str) {
+        self.line('-', line);
+        self.old_count += 1;
+    }
+
+    fn render(self, result: &mut String) {
+        let _ = writeln!(
+            result,
+            "@@ -{} +{} @@",
+            range(self.old_start, self.old_count),
+            range(self.new_start, self.new_count)
+        );
+        result.push_str(&self.lines);
+    }
+}
+
+fn compare(original: &[&str], modified: &[&str], edits: &mut Vec<Edit>) {
+    let prefix = original
+        .iter()
+        .zip(modified)
+        .take_while(|(line, other)| line == other)
+        .count();
+    let (original, modified) = (&original[prefix..], &modified[prefix..]);
+    let suffix = original
+        .iter()
+        .rev()
+        .zip(modified.iter().rev())
+        .take_while(|(line, other)| line == other)
+        .count();
+    let original = &original[..original.len() - suffix];
+    let modified = &modified[..modified.len() - suffix];
+
+    edits.extend(std::iter::repeat_n(Edit::Equal, prefix));
+
+    if let Some((row, column)) = middle(original, modified) {
+        compare(&original[..row], &modified[..column], edits);
+        compare(&original[row..], &modified[column..], edits);
+    } else {
+        edits.extend(std::iter::repeat_n(Edit::Delete, original.len()));
+        edits.extend(std::iter::repeat_n(Edit::Insert, modified.len()));
+    }
+
+    edits.extend(std::iter::repeat_n(Edit::Equal, suffix));
+}
+
+fn lines(s: &str) -> Vec<&str> {
+    s.split_inclusive('\n').collect()
+}
+
+/// Find a point on a shortest edit path by searching from both ends.
+///
+/// Only the furthest reaching paths of the current distance are kept such
+/// that the memory is linear in the size of the input.  [`None`] means that
+/// there is nothing to split, i.e. one of both sides is empty.
+fn middle(original: &[&str], modified: &[&str]) -> Option<(usize, usize)> {
+    let (rows, columns) = (original.len(), modified.len());
+
+    if rows == 0 || columns == 0 {
+        return None;
+    }
+
+    let limit = (rows + columns).div_ceil(2);
+    let offset = limit + 1;
+    let odd = (rows + columns) % 2 == 1;
+    let length = 2 * offset + 2;
+    let mut forward = vec![None; length];
+    let mut backward = forward.clone();
+    let (mut forward_start, mut forward_end) = (0, 0);
+    let (mut backward_start, mut backward_end) = (0, 0);
+    let opposite = |diagonal: usize| {
+        (2 * offset + rows)
+            .checked_sub(columns + diagonal)
+            .filter(|&opposite| opposite < length)
+    };
+    let next =
+        |frontier: &[Option<usize>], distance: usize, diagonal: usize| {
+            if diagonal + distance == offset
+                || (diagonal != offset + distance
+                    && frontier[diagonal - 1] < frontier[diagonal + 1])
+            {
+                frontier[diagonal + 1].unwrap_or_default()
+            } else {
+                frontier[diagonal - 1].map_or(0, |row| row + 1)
+            }
+        };
+
+    forward[offset + 1] = Some(0);
+    backward[offset + 1] = Some(0);
+
+    for distance in 0..limit {
+        for diagonal in (offset + forward_start - distance
+            ..=offset + distance - forward_end)
+            .step_by(2)
+        {
+            let mut row = next(&forward, distance, diagonal);
+            let mut column = row + offset - diagonal;
+            let snake = original
+                .get(row..)
+                .unwrap_or_default()
+                .iter()
+                .zip(modified.get(column..).unwrap_or_default())
+                .take_while(|(line, other)| line == other)
+                .count();
+
+            row += snake;
+            column += snake;
+            forward[diagonal] = Some(row);
+
+            if row > rows {
+                forward_end += 2;
+            } else if column > columns {
+                forward_start += 2;
+            } else if odd
+                && opposite(diagonal)
+                    .and_then(|opposite| backward[opposite])
+                    .is_some_and(|reverse| row + reverse >= rows)
+            {
+                return Some((row, column));
+            }
+        }
+
+        for diagonal in (offset + backward_start - distance
+            ..=offset + distance - backward_end)
+            .step_by(2)
+        {
+            let mut row = next(&backward, distance, diagonal);
+            let column = row + offset - diagonal;
+
+            row += original[..rows - row.min(rows)]
+                .iter()
+                .rev()
+                .zip(modified[..columns - column.min(columns)].iter().rev())
+                .take_while(|(line, other)| line == other)
+                .count();
+            backward[diagonal] = Some(row);
+
+            if row > rows {
+                backward_end += 2;
+            } else if row + offset - diagonal > columns {
+                backward_start += 2;
+            } else if !odd {
+                if let Some((opposite, split)) =
+                    opposite(diagonal).and_then(|opposite| {
+                        forward[opposite].map(|split| (opposite, split))
+                    })
+                {
+                    if split + row >= rows {
+                        return Some((split, split + offset - opposite));
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn myers(original: &[&str], modified: &[&str]) -> Vec<Edit> {
+    let mut edits = Vec::with_capacity(original.len() + modified.len());
+
+    compare(original, modified, &mut edits);
+    edits
+}
+
+fn range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{count}", start + 1),
+    }
+}
+
+/******************************************************************************/
@@ -0,0 +1,754 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    writing::text, Cause, Check, Error, Markers, NullSink, Operation,
+    PathBufLikeReader, PathBufLikeTruncation, Payload, Result, Sink,
+    StderrSink, Step,
+};
+use std::path::{Path, PathBuf};
+
+/// The kinds of Rust documentation comments.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DocComment {
+    /// An inner block comment, starting with `/*!` and ending with `*/`.
+    ///
+    /// The opening and closing delimiters are placed on lines of their own.
+    Block,
+
+    /// Inner line comments, starting with `//!`.
+    #[default]
+    Inner,
+
+    /// Outer line comments, starting with `///`.
+    Outer,
+}
+
+impl DocComment {
+    fn extract(self, block: &str) -> String {
+        match self {
+            Self::Block => {
+                let inner = block.trim_start();
+                let inner = &inner[3..inner.rfind("*/").unwrap_or(inner.len())];
+                let last = inner.matches('\n').count();
+
+                inner
+                    .split('\n')
+                    .enumerate()
+                    .filter(|&(index, line)| {
+                        (index != 0 && index != last) || !line.trim().is_empty()
+                    })
+                    .flat_map(|(index, line)| {
+                        let line = match index {
+                            0 => line.trim(),
+                            _ if index == last => line.trim_end(),
+                            _ => line,
+                        };
+
+                        [line, "\n"]
+                    })
+                    .collect()
+            }
+            Self::Inner | Self::Outer => block
+                .lines()
+                .flat_map(|line| {
+                    let line = &line.trim_start()[3..];
+                    [line.strip_prefix(' ').unwrap_or(line), "\n"]
+                })
+                .collect(),
+        }
+    }
+
+    fn is_line(self, line: &str) -> bool {
+        let line = line.trim_start();
+
+        match self {
+            Self::Block => false,
+            Self::Inner => line.starts_with("//!"),
+            Self::Outer => line.starts_with("///") && !line.starts_with("////"),
+        }
+    }
+
+    fn render(self, markdown: &str, indentation: &str) -> String {
+        match self {
+            Self::Block => {
+                let mut result = format!("{indentation}/*!\n{markdown}");
+
+                if !markdown.is_empty() && !markdown.ends_with('\n') {
+                    result.push('\n');
+                }
+
+                result + indentation + "*/\n"
+            }
+            Self::Inner | Self::Outer => {
+                let marker = if self == Self::Inner { "//!" } else { "///" };
+
+                markdown
+                    .lines()
+                    .map(|line| {
+                        if line.is_empty() {
+                            format!("{indentation}{marker}\n")
+                        } else {
+                            format!("{indentation}{marker} {line}\n")
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn spans<'a>(self, content: &'a str, path: &Path) -> Result<Vec<Span<'a>>> {
+        let mut spans = Vec::new();
+        let mut offset = 0;
+        let mut begin = None;
+
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            match (self, begin) {
+                (Self::Block, None) if line.trim_start().starts_with("/*!") => {
+                    if line.trim_start()[3..].contains("*/") {
+                        spans.push(Span::new(
+                            content,
+                            offset,
+                            offset + line.len(),
+                            index + 1,
+                        ));
+                    } else {
+                        begin = Some((offset, index));
+                    }
+                }
+                (Self::Block, Some((start, first))) if line.contains("*/") => {
+                    spans.push(Span::new(
+                        content,
+                        start,
+                        offset + line.len(),
+                        first + 1,
+                    ));
+                    begin = None;
+                }
+                (Self::Inner | Self::Outer, None) if self.is_line(line) => {
+                    begin = Some((offset, index));
+                }
+                (Self::Inner | Self::Outer, Some((start, first)))
+                    if !self.is_line(line) =>
+                {
+                    spans.push(Span::new(content, start, offset, first + 1));
+                    begin = None;
+                }
+                _ => {}
+            }
+
+            offset += line.len();
+        }
+
+        match (self, begin) {
+            (_, None) => Ok(spans),
+            (Self::Block, Some((_, index))) => Err(malformed(
+                path,
+                index + 1,
+                "unterminated documentation comment".to_string(),
+            )),
+            (Self::Inner | Self::Outer, Some((start, first))) => {
+                spans.push(Span::new(content, start, content.len(), first + 1));
+                Ok(spans)
+            }
+        }
+    }
+}
+
+/// The location of a documentation comment within a file.
+///
+/// Comments inserted at an item, a line or a region are indented just like
+/// the line following them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum DocLocation {
+    /// The first documentation comment of the file.
+    ///
+    /// A missing comment will be inserted after the leading comments of the
+    /// file, such as a license header, and the following blank lines.
+    #[default]
+    First,
+
+    /// The documentation comment directly preceding the given item.
+    ///
+    /// Items are declared by `const`, `enum`, `fn`, `macro_rules!`, `mod`,
+    /// `static`, `struct`, `trait`, `type` or `union`, followed by the given
+    /// name.  Attributes between the comment and the item are skipped.  A
+    /// missing comment will be inserted before the attributes of the item.
+    Item(String),
+
+    /// The documentation comment covering the given line, starting at one.
+    ///
+    /// A missing comment will be inserted before the given line.  The line
+    /// after the last one addresses the end of the file.
+    Line(usize),
+
+    /// The first documentation comment within the given region.
+    ///
+    /// The region is delimited by the given [`crate::Markers`].  A missing
+    /// comment will be inserted at the beginning of the region.
+    Region(String, Markers),
+}
+
+impl DocLocation {
+    fn resolve<'a>(
+        &self,
+        kind: DocComment,
+        content: &'a str,
+        path: &Path,
+    ) -> Result<Target<'a>> {
+        let spans = kind.spans(content, path)?;
+        let at = |offset: usize| {
+            let line = &content[offset..];
+            let line = &line[..line.find('\n').unwrap_or(line.len())];
+
+            Target::Insertion {
+                indentation: &line[..line.len() - line.trim_start().len()],
+                offset,
+                separator: "",
+            }
+        };
+
+        Ok(match self {
+            Self::First => spans.into_iter().next().map_or_else(
+                || {
+                    let offset = insertion(content);
+
+                    Target::Insertion {
+                        indentation: "",
+                        offset,
+                        separator: if offset < content.len() {
+                            "\n"
+                        } else {
+                            ""
+                        },
+                    }
+                },
+                Target::Span,
+            ),
+            Self::Item(name) => {
+                let lines: Vec<_> = content.split_inclusive('\n').collect();
+                let mut index = lines
+                    .iter()
+                    .position(|line| declares(line, name))
+                    .ok_or_else(|| {
+                        malformed(
+                            path,
+                            lines.len() + 1,
+                            format!("there is no item named '{name}'"),
+                        )
+                    })?;
+
+                while index > 0
+                    && lines[index - 1].trim_start().starts_with("#[")
+                {
+                    index -= 1;
+                }
+
+                let offset = lines[..index].iter().map(|l| l.len()).sum();
+
+                spans
+                    .into_iter()
+                    .find(|span| span.end == offset)
+                    .map_or_else(|| at(offset), Target::Span)
+            }
+            Self::Line(line) => {
+                let lines = content.split_inclusive('\n').count();
+
+                if *line == 0 || *line > lines + 1 {
+                    return Err(malformed(
+                        path,
+                        lines + 1,
+                        format!("there is no line {line}"),
+                    ));
+                }
+
+                spans
+                    .into_iter()
+                    .find(|span| (span.line..=span.last).contains(line))
+                    .map_or_else(
+                        || {
+                            at(content
+                                .split_inclusive('\n')
+                                .take(line - 1)
+                                .map(str::len)
+                                .sum())
+                        },
+                        Target::Span,
+                    )
+            }
+            Self::Region(name, markers) => {
+                let (begin, end) =
+                    markers.bounds(content, name, Operation::Read, path)?;
+
+                spans
+                    .into_iter()
+                    .find(|span| span.begin >= begin && span.end <= end)
+                    .map_or_else(|| at(begin), Target::Span)
+            }
+        })
+    }
+}
+
+/// Read documentation comments from files given as instances convertible to
+/// a [`std::path::PathBuf`].
+pub trait DocumentationReader {
+    /// Read the documentation comment at the given location as Markdown.
+    ///
+    /// This method behaves just like
+    /// [`crate::DocumentationReader::read_documentation_silently`] despite
+    /// also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_documentation_loudly(
+        &self,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<String>;
+
+    /// Read the documentation comment at the given location as Markdown.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and searched for the block
+    /// of consecutive documentation comments of the given
+    /// [`crate::DocComment`] at the given [`crate::DocLocation`].  The comment
+    /// markers as well as one following space will be removed from each line
+    /// such that bare markers result in blank lines.  Text on the lines of the
+    /// delimiters of block comments is kept, such that `/*! Title */` results
+    /// in `Title`.
+    ///
+    /// The return value is either the Markdown as a [`String`], in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    /// If there is no such comment, the [`String`] is empty.  Missing items,
+    /// lines and regions are reported as [`crate::Cause::Parse`].
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_documentation_silently(
+        &self,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<String>;
+
+    /// Read the documentation comment at the given location as Markdown.
+    ///
+    /// This method behaves just like
+    /// [`crate::DocumentationReader::read_documentation_silently`] despite
+    /// also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_documentation_with<S: Sink>(
+        &self,
+        kind: DocComment,
+        location: &DocLocation,
+        sink: S,
+    ) -> Result<String>;
+}
+
+impl<T> DocumentationReader for T
+where
+    PathBuf: From<T>,
+    T: Clone,
+{
+    fn read_documentation_loudly(
+        &self,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<String> {
+        self.read_documentation_with(kind, location, StderrSink)
+    }
+
+    fn read_documentation_silently(
+        &self,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<String> {
+        self.read_documentation_with(kind, location, NullSink)
+    }
+
+    fn read_documentation_with<S: Sink>(
+        &self,
+        kind: DocComment,
+        location: &DocLocation,
+        mut sink: S,
+    ) -> Result<String> {
+        let path = PathBuf::from(self.clone());
+        let content = path.read_with(&mut sink)?;
+
+        Ok(
+            match location
+                .resolve(kind, &content, &path)
+                .inspect_err(|e| sink.report(e))?
+            {
+                Target::Insertion { .. } => String::new(),
+                Target::Span(span) => {
+                    kind.extract(&content[span.begin..span.end])
+                }
+            },
+        )
+    }
+}
+
+/// Mirror Markdown into the documentation comments of files given as
+/// instances convertible to a [`std::path::PathBuf`].
+pub trait DocumentationTruncation<T>
+where
+    PathBuf: From<T>,
+{
+    /// Check whether the documentation comment of the given file is up to
+    /// date.
+    ///
+    /// This method behaves just like
+    /// [`crate::DocumentationTruncation::check_documentation_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_documentation_loudly(
+        self,
+        destination: T,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<Check>;
+
+    /// Check whether the documentation comment of the given file is up to
+    /// date.
+    ///
+    /// The data this method is called on will be interpreted as UTF-8 text and
+    /// compared with the Markdown
+    /// [`crate::DocumentationReader::read_documentation_silently`] extracts
+    /// from the given location of the given file.  The file will not be
+    /// written to.
+    ///
+    /// The return value is either a [`crate::Check`] to describe whether the
+    /// documentation drifted, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  Files without such a comment are
+    /// reported as [`crate::Check::WouldCreate`].  Data which is no valid
+    /// UTF-8 is reported as [`std::io::ErrorKind::InvalidData`].
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_documentation_silently(
+        self,
+        destination: T,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<Check>;
+
+    /// Check whether the documentation comment of the given file is up to
+    /// date.
+    ///
+    /// This method behaves just like
+    /// [`crate::DocumentationTruncation::check_documentation_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_documentation_with<S: Sink>(
+        self,
+        destination: T,
+        kind: DocComment,
+        location: &DocLocation,
+        sink: S,
+    ) -> Result<Check>;
+
+    /// Replace the documentation comment of the given file.
+    ///
+    /// This method behaves just like
+    /// [`crate::DocumentationTruncation::truncate_documentation_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_documentation_loudly(
+        self,
+        destination: T,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<()>;
+
+    /// Replace the documentation comment of the given file.
+    ///
+    /// The data this method is called on will be interpreted as UTF-8 text and
+    /// rendered as documentation comment of the given [`crate::DocComment`].
+    /// Blank lines of line comments will consist of the bare marker.  The
+    /// rendered comment replaces the comment of this kind at the given
+    /// [`crate::DocLocation`] of the given file, keeping its indentation.  If
+    /// there is none, it will be inserted where the [`crate::DocLocation`]
+    /// describes.  The file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and truncated by
+    /// [`crate::PathBufLikeTruncation::truncate_silently`].
+    ///
+    /// The return value is either the unit type, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.  Data which
+    /// is no valid UTF-8 is reported as [`std::io::ErrorKind::InvalidData`].
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_documentation_silently(
+        self,
+        destination: T,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<()>;
+
+    /// Replace the documentation comment of the given file.
+    ///
+    /// This method behaves just like
+    /// [`crate::DocumentationTruncation::truncate_documentation_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_documentation_with<S: Sink>(
+        self,
+        destination: T,
+        kind: DocComment,
+        location: &DocLocation,
+        sink: S,
+    ) -> Result<()>;
+}
+
+impl<P, T: Payload> DocumentationTruncation<P> for T
+where
+    PathBuf: From<P>,
+{
+    fn check_documentation_loudly(
+        self,
+        destination: P,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<Check> {
+        self.check_documentation_with(destination, kind, location, StderrSink)
+    }
+
+    fn check_documentation_silently(
+        self,
+        destination: P,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<Check> {
+        self.check_documentation_with(destination, kind, location, NullSink)
+    }
+
+    fn check_documentation_with<S: Sink>(
+        self,
+        destination: P,
+        kind: DocComment,
+        location: &DocLocation,
+        mut sink: S,
+    ) -> Result<Check> {
+        let path = PathBuf::from(destination);
+        let content = path.read_with(&mut sink)?;
+        let markdown =
+            text(&self, Operation::Read).inspect_err(|e| sink.report(e))?;
+
+        Ok(
+            match location
+                .resolve(kind, &content, &path)
+                .inspect_err(|e| sink.report(e))?
+            {
+                Target::Insertion { .. } => Check::WouldCreate,
+                Target::Span(span)
+                    if kind.extract(&content[span.begin..span.end])
+                        == kind.extract(&kind.render(&markdown, "")) =>
+                {
+                    Check::Unchanged
+                }
+                Target::Span(_) => Check::WouldModify,
+            },
+        )
+    }
+
+    fn truncate_documentation_loudly(
+        self,
+        destination: P,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<()> {
+        self.truncate_documentation_with(
+            destination,
+            kind,
+            location,
+            StderrSink,
+        )
+    }
+
+    fn truncate_documentation_silently(
+        self,
+        destination: P,
+        kind: DocComment,
+        location: &DocLocation,
+    ) -> Result<()> {
+        self.truncate_documentation_with(destination, kind, location, NullSink)
+    }
+
+    fn truncate_documentation_with<S: Sink>(
+        self,
+        destination: P,
+        kind: DocComment,
+        location: &DocLocation,
+        mut sink: S,
+    ) -> Result<()> {
+        let path = PathBuf::from(destination);
+        let content = path.read_with(&mut sink)?;
+        let markdown =
+            text(&self, Operation::Truncate).inspect_err(|e| sink.report(e))?;
+
+        let (begin, end, indentation, separator) = match location
+            .resolve(kind, &content, &path)
+            .inspect_err(|e| sink.report(e))?
+        {
+            Target::Insertion {
+                indentation,
+                offset,
+                separator,
+            } => (offset, offset, indentation, separator),
+            Target::Span(span) => (span.begin, span.end, span.indentation, ""),
+        };
+        let result = content[..begin].to_string()
+            + &kind.render(&markdown, indentation)
+            + separator
+            + &content[end..];
+
+        PathBufLikeTruncation::<PathBuf>::truncate_with(result, path, sink)
+    }
+}
+
+struct Span<'a> {
+    begin: usize,
+    end: usize,
+    indentation: &'a str,
+    last: usize,
+    line: usize,
+}
+
+impl<'a> Span<'a> {
+    fn new(content: &'a str, begin: usize, end: usize, line: usize) -> Self {
+        let text = &content[begin..];
+
+        Self {
+            begin,
+            end,
+            indentation: &text[..text.len() - text.trim_start().len()],
+            last: line
+                + content[begin..end.max(begin + 1) - 1].matches('\n').count(),
+            line,
+        }
+    }
+}
+
+enum Target<'a> {
+    Insertion {
+        indentation: &'a str,
+        offset: usize,
+        separator: &'static str,
+    },
+    Span(Span<'a>),
+}
+
+fn declares(line: &str, name: &str) -> bool {
+    const ITEMS: [&str; 10] = [
+        "const",
+        "enum",
+        "fn",
+        "macro_rules!",
+        "mod",
+        "static",
+        "struct",
+        "trait",
+        "type",
+        "union",
+    ];
+
+    let mut previous = "";
+
+    !line.trim_start().starts_with("//")
+        && line
+            .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '!')
+            .filter(|word| !word.is_empty())
+            .any(|word| {
+                let found = ITEMS.contains(&previous) && word == name;
+                previous = word;
+                found
+            })
+}
+
+fn insertion(content: &str) -> usize {
+    let mut offset = 0;
+    let mut block = false;
+    let mut header = true;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let comment = if block {
+            block = !trimmed.ends_with("*/");
+            true
+        } else if header && trimmed.starts_with("/*") {
+            block = !trimmed[2..].ends_with("*/");
+            true
+        } else {
+            header && trimmed.starts_with("//")
+        };
+
+        if !comment {
+            if trimmed.is_empty() {
+                header = false;
+            } else {
+                break;
+            }
+        }
+
+        offset += line.len();
+    }
+
+    offset
+}
+
+fn malformed(path: &Path, line: usize, reason: String) -> Error {
+    Error::new(
+        Operation::Read,
+        Step::Parse,
+        Some(path.to_path_buf()),
+        Cause::Parse {
+            line,
+            column: 1,
+            reason,
+        },
+    )
+}
+
+/******************************************************************************/
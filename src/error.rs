@@ -0,0 +1,361 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use std::path::{Path, PathBuf};
+
+/// The result type of this crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The reason why an operation failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Cause {
+    /// The underlying I/O operation failed after writing the data partially.
+    Incomplete {
+        /// The error which stopped the writing.
+        error: std::io::Error,
+
+        /// The number of bytes which should have been written, if known.
+        ///
+        /// The size of data which is streamed to the destination is only known
+        /// once all of it was passed to the destination.
+        expected: Option<usize>,
+
+        /// The number of bytes which were written before the failure.
+        written: usize,
+    },
+
+    /// The underlying I/O operation failed.
+    Io(std::io::Error),
+
+    /// The data does not follow the expected syntax.
+    Parse {
+        /// The line in which the problem was found, starting at one.
+        line: usize,
+
+        /// The column in which the problem was found, starting at one.
+        column: usize,
+
+        /// The description of the problem.
+        reason: String,
+    },
+
+    /// The data could only be written partially.
+    PartialWrite {
+        /// The number of bytes which should have been written, if known.
+        expected: Option<usize>,
+
+        /// The number of bytes which were actually written.
+        written: usize,
+    },
+}
+
+/// An error which occurred while reading or writing.
+///
+/// Instances of this type record which [`crate::Operation`] was requested,
+/// which [`crate::Step`] of it failed, the affected file, if any, and the
+/// [`crate::Cause`] of the failure.  In order to keep functions returning a
+/// [`sysexits::Result`] working, errors can be converted into a
+/// [`sysexits::ExitCode`].
+#[derive(Debug)]
+pub struct Error {
+    cause: Cause,
+    operation: Operation,
+    path: Option<PathBuf>,
+    step: Step,
+}
+
+impl Error {
+    /// The reason why the operation failed.
+    #[must_use]
+    pub const fn cause(&self) -> &Cause {
+        &self.cause
+    }
+
+    /// The [`sysexits::ExitCode`] which describes this error best.
+    ///
+    /// The mapping follows the conventions of `sysexits.h` and considers the
+    /// context in which the error occurred:
+    ///
+    /// | Condition                                     | Exit code     |
+    /// |:----------------------------------------------|:--------------|
+    /// | missing permissions                           | `NoPerm`      |
+    /// | malformed data, including invalid UTF-8       | `DataErr`     |
+    /// | partial writes                                | `IoErr`       |
+    /// | [`crate::Operation::Read`]                    | `NoInput`     |
+    /// | opening a file for appending or truncation    | `CantCreat`   |
+    /// | any other failure                             | `IoErr`       |
+    ///
+    /// The conditions are checked from top to bottom, the first match wins.
+    /// Hence, failures of [`crate::PathBufLikeReader`] and
+    /// [`crate::VectorReader`] result in `NoInput`, unless permissions are
+    /// missing or the data is no valid UTF-8, whereas failures to create or
+    /// open the destinations of [`crate::PathBufLikeAppendix`] and
+    /// [`crate::PathBufLikeTruncation`] result in `CantCreat`.
+    #[must_use]
+    pub fn exit_code(&self) -> sysexits::ExitCode {
+        match (&self.cause, self.operation, self.step) {
+            (Cause::Io(e), _, _)
+                if e.kind() == std::io::ErrorKind::PermissionDenied =>
+            {
+                sysexits::ExitCode::NoPerm
+            }
+            (Cause::Io(e), _, _)
+                if e.kind() == std::io::ErrorKind::InvalidData =>
+            {
+                sysexits::ExitCode::DataErr
+            }
+            (Cause::Parse { .. }, _, _) => sysexits::ExitCode::DataErr,
+            (Cause::Incomplete { .. } | Cause::PartialWrite { .. }, _, _) => {
+                sysexits::ExitCode::IoErr
+            }
+            (_, Operation::Read, _) => sysexits::ExitCode::NoInput,
+            (_, Operation::Append | Operation::Truncate, Step::Open) => {
+                sysexits::ExitCode::CantCreat
+            }
+            _ => sysexits::ExitCode::IoErr,
+        }
+    }
+
+    /// The [`std::io::ErrorKind`] of the underlying I/O error, if any.
+    #[must_use]
+    pub fn kind(&self) -> Option<std::io::ErrorKind> {
+        match &self.cause {
+            Cause::Incomplete { error: e, .. } | Cause::Io(e) => Some(e.kind()),
+            Cause::Parse { .. } | Cause::PartialWrite { .. } => None,
+        }
+    }
+
+    /// The description of this error without the affected file.
+    #[must_use]
+    pub fn message(&self) -> String {
+        match &self.cause {
+            Cause::Incomplete {
+                error,
+                expected,
+                written,
+            } => format!(
+                "writing failed after {}: {error}",
+                progress(*written, *expected)
+            ),
+            Cause::Io(e) => match self.step {
+                Step::Open => {
+                    format!("opening for {} failed: {e}", self.operation)
+                }
+                Step::Parse => format!("parsing failed: {e}"),
+                Step::Read => format!("reading failed: {e}"),
+                Step::Remove => format!("removing failed: {e}"),
+                Step::Rename => format!("replacing failed: {e}"),
+                Step::Synchronise => {
+                    format!("synchronising the directory failed: {e}")
+                }
+                Step::Write => format!("writing failed: {e}"),
+            },
+            Cause::Parse {
+                line,
+                column,
+                reason,
+            } => format!(
+                "parsing failed in line {line}, column {column}: {reason}"
+            ),
+            Cause::PartialWrite { expected, written } => format!(
+                "creating an exact copy was not possible: only {} were written",
+                progress(*written, *expected)
+            ),
+        }
+    }
+
+    /// Create a new instance.
+    #[must_use]
+    pub const fn new(
+        operation: Operation,
+        step: Step,
+        path: Option<PathBuf>,
+        cause: Cause,
+    ) -> Self {
+        Self {
+            cause,
+            operation,
+            path,
+            step,
+        }
+    }
+
+    /// The operation which failed.
+    #[must_use]
+    pub const fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// The file which was affected, if any.
+    ///
+    /// In case of streams, such as [`std::io::Stdin`], there is no path.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The step of the operation which failed.
+    #[must_use]
+    pub const fn step(&self) -> Step {
+        self.step
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let target = self.path.as_ref().map_or_else(
+            || "the stream".to_string(),
+            |p| format!("'{}'", p.display()),
+        );
+
+        match &self.cause {
+            Cause::Incomplete {
+                error,
+                expected,
+                written,
+            } => write!(
+                f,
+                "Writing to {target} failed after {}: {error}",
+                progress(*written, *expected)
+            ),
+            Cause::Io(e) => match self.step {
+                Step::Open => write!(
+                    f,
+                    "Opening {target} for {} failed: {e}",
+                    self.operation
+                ),
+                Step::Parse => write!(f, "Parsing {target} failed: {e}"),
+                Step::Read => write!(f, "Reading from {target} failed: {e}"),
+                Step::Remove => write!(f, "Removing {target} failed: {e}"),
+                Step::Rename => write!(f, "Replacing {target} failed: {e}"),
+                Step::Synchronise => write!(
+                    f,
+                    "Synchronising the directory of {target} failed: {e}"
+                ),
+                Step::Write => write!(f, "Writing to {target} failed: {e}"),
+            },
+            Cause::Parse {
+                line,
+                column,
+                reason,
+            } => write!(
+                f,
+                "Parsing {target} failed in line {line}, column {column}: \
+                 {reason}."
+            ),
+            Cause::PartialWrite { expected, written } => write!(
+                f,
+                "Creating an exact copy in {target} was not possible: only {} \
+                 were written.",
+                progress(*written, *expected)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.cause {
+            Cause::Incomplete { error: e, .. } | Cause::Io(e) => Some(e),
+            Cause::Parse { .. } | Cause::PartialWrite { .. } => None,
+        }
+    }
+}
+
+impl From<Error> for sysexits::ExitCode {
+    fn from(error: Error) -> Self {
+        error.exit_code()
+    }
+}
+
+/// The operations this crate performs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Operation {
+    /// Append data to a file.
+    Append,
+
+    /// Apply changes to files.
+    Patch,
+
+    /// Read data from a file or stream.
+    Read,
+
+    /// Remove a file.
+    Remove,
+
+    /// Truncate a file and write data to it.
+    Truncate,
+
+    /// Write data to a stream.
+    Write,
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Append => "appending",
+                Self::Patch => "patching",
+                Self::Read => "reading",
+                Self::Remove => "removal",
+                Self::Truncate => "truncation",
+                Self::Write => "writing",
+            }
+        )
+    }
+}
+
+/// The steps an operation consists of.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Step {
+    /// Open the file to operate on.
+    Open,
+
+    /// Interpret the data which was read.
+    Parse,
+
+    /// Read data from the opened file or stream.
+    Read,
+
+    /// Remove a file which is no longer needed.
+    Remove,
+
+    /// Replace the file with a temporary one which holds the new data.
+    Rename,
+
+    /// Persist the replacement of the file in its directory.
+    Synchronise,
+
+    /// Write data to the opened file or stream.
+    Write,
+}
+
+fn progress(written: usize, expected: Option<usize>) -> String {
+    expected.map_or_else(
+        || format!("{written} bytes"),
+        |expected| format!("{written} of {expected} bytes"),
+    )
+}
+
+/******************************************************************************/
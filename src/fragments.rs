@@ -0,0 +1,491 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    writing::write, Cause, ChangelogReader, Error, Event, Fragment, NullSink,
+    Operation, PathBufLikeTruncation, Result, Retry, Section, Sink, StderrSink,
+    Step, Version,
+};
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Manage the changelog fragments in directories given as instances
+/// convertible to a [`std::path::PathBuf`].
+///
+/// Fragments are RON files holding a single [`crate::Fragment`], usually one
+/// per change.  Their file names start with the UTC time of their creation,
+/// such as `20240606_114935_slug.ron`, such that they sort chronologically.
+pub trait FragmentDirectory {
+    /// Read and merge all fragments of the directory this method is called
+    /// on.
+    ///
+    /// This method behaves just like
+    /// [`crate::FragmentDirectory::read_fragments_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_fragments_loudly(&self) -> Result<(Fragment, Vec<PathBuf>)>;
+
+    /// Read and merge all fragments of the directory this method is called
+    /// on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  All files of the referenced directory ending
+    /// in `.ron` will be read by
+    /// [`crate::ChangelogReader::read_fragment_silently`] in the order of
+    /// their names and merged by [`crate::Fragment::merge`].  Hence, the
+    /// changes are grouped and sorted by category.
+    ///
+    /// The return value is either the merged [`crate::Fragment`] together
+    /// with the files which were read, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_fragments_silently(&self) -> Result<(Fragment, Vec<PathBuf>)>;
+
+    /// Read and merge all fragments of the directory this method is called
+    /// on.
+    ///
+    /// This method behaves just like
+    /// [`crate::FragmentDirectory::read_fragments_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_fragments_with<S: Sink>(
+        &self,
+        sink: S,
+    ) -> Result<(Fragment, Vec<PathBuf>)>;
+
+    /// Move all fragments of the directory this method is called on into a
+    /// new section of the given changelog.
+    ///
+    /// This method behaves just like
+    /// [`crate::FragmentDirectory::release_fragments_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn release_fragments_loudly<P>(
+        &self,
+        changelog: P,
+        version: Version,
+    ) -> Result<Section>
+    where
+        PathBuf: From<P>;
+
+    /// Move all fragments of the directory this method is called on into a
+    /// new section of the given changelog.
+    ///
+    /// The fragments will be read and merged by
+    /// [`crate::FragmentDirectory::read_fragments_silently`].  The given
+    /// changelog will be read by
+    /// [`crate::ChangelogReader::read_changelog_silently`], a new
+    /// [`crate::Section`] with the given version, the current UTC time and
+    /// the merged changes will be prepended, and the result will be written
+    /// by [`crate::PathBufLikeTruncation::truncate_atomically_silently`].
+    /// The fragments will only be removed after the changelog was written
+    /// successfully.  Otherwise, they are kept such that the release can be
+    /// repeated.
+    ///
+    /// The return value is either the new [`crate::Section`], in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn release_fragments_silently<P>(
+        &self,
+        changelog: P,
+        version: Version,
+    ) -> Result<Section>
+    where
+        PathBuf: From<P>;
+
+    /// Move all fragments of the directory this method is called on into a
+    /// new section of the given changelog.
+    ///
+    /// This method behaves just like
+    /// [`crate::FragmentDirectory::release_fragments_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn release_fragments_with<P, S: Sink>(
+        &self,
+        changelog: P,
+        version: Version,
+        sink: S,
+    ) -> Result<Section>
+    where
+        PathBuf: From<P>;
+
+    /// Write the given fragment into the directory this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::FragmentDirectory::write_fragment_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn write_fragment_loudly(
+        &self,
+        fragment: &Fragment,
+        slug: &str,
+    ) -> Result<PathBuf>;
+
+    /// Write the given fragment into the directory this method is called on.
+    ///
+    /// The directory will be created, if necessary.  The name of the new file
+    /// consists of the current UTC time, the given slug and the extension
+    /// `.ron`.  In case this name is already taken, a counter will be
+    /// appended to the slug.  The file is created exclusively such that
+    /// concurrent calls never write the same file.  Slugs containing path
+    /// separators or `..` are rejected as [`std::io::ErrorKind::InvalidInput`].
+    ///
+    /// The return value is either the path of the new file, in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn write_fragment_silently(
+        &self,
+        fragment: &Fragment,
+        slug: &str,
+    ) -> Result<PathBuf>;
+
+    /// Write the given fragment into the directory this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::FragmentDirectory::write_fragment_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn write_fragment_with<S: Sink>(
+        &self,
+        fragment: &Fragment,
+        slug: &str,
+        sink: S,
+    ) -> Result<PathBuf>;
+}
+
+impl<T> FragmentDirectory for T
+where
+    PathBuf: From<T>,
+    T: Clone,
+{
+    fn read_fragments_loudly(&self) -> Result<(Fragment, Vec<PathBuf>)> {
+        self.read_fragments_with(StderrSink)
+    }
+
+    fn read_fragments_silently(&self) -> Result<(Fragment, Vec<PathBuf>)> {
+        self.read_fragments_with(NullSink)
+    }
+
+    fn read_fragments_with<S: Sink>(
+        &self,
+        mut sink: S,
+    ) -> Result<(Fragment, Vec<PathBuf>)> {
+        let directory = PathBuf::from(self.clone());
+        let path = directory.as_path();
+        let error = |step| {
+            move |e| {
+                Error::new(
+                    Operation::Read,
+                    step,
+                    Some(path.to_path_buf()),
+                    Cause::Io(e),
+                )
+            }
+        };
+        let mut files = std::fs::read_dir(&directory)
+            .map_err(error(Step::Open))
+            .and_then(|entries| {
+                entries
+                    .map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<std::io::Result<Vec<_>>>()
+                    .map_err(error(Step::Read))
+            })
+            .inspect_err(|e| sink.report(e))?;
+
+        files.retain(|file| {
+            file.is_file() && file.extension().is_some_and(|e| e == "ron")
+        });
+        files.sort();
+
+        let mut fragment = Fragment::new();
+
+        for file in &files {
+            fragment
+                .merge_from(file.read_fragment_with(&mut sink)?, Some(file))
+                .inspect_err(|e| sink.report(e))?;
+        }
+
+        Ok((fragment, files))
+    }
+
+    fn release_fragments_loudly<P>(
+        &self,
+        changelog: P,
+        version: Version,
+    ) -> Result<Section>
+    where
+        PathBuf: From<P>,
+    {
+        self.release_fragments_with(changelog, version, StderrSink)
+    }
+
+    fn release_fragments_silently<P>(
+        &self,
+        changelog: P,
+        version: Version,
+    ) -> Result<Section>
+    where
+        PathBuf: From<P>,
+    {
+        self.release_fragments_with(changelog, version, NullSink)
+    }
+
+    fn release_fragments_with<P, S: Sink>(
+        &self,
+        changelog: P,
+        version: Version,
+        mut sink: S,
+    ) -> Result<Section>
+    where
+        PathBuf: From<P>,
+    {
+        let path = PathBuf::from(changelog);
+        let (fragment, files) = self.read_fragments_with(&mut sink)?;
+        let mut changelog = path.read_changelog_with(&mut sink)?;
+        let section =
+            Section::new(version, &Timestamp::now().rfc3339(), fragment);
+
+        changelog.prepend(section.clone());
+        PathBufLikeTruncation::<PathBuf>::truncate_atomically_with(
+            changelog, path, &mut sink,
+        )?;
+
+        for file in files {
+            std::fs::remove_file(&file)
+                .map_err(|e| {
+                    Error::new(
+                        Operation::Remove,
+                        Step::Remove,
+                        Some(file.clone()),
+                        Cause::Io(e),
+                    )
+                })
+                .inspect_err(|e| sink.report(e))?;
+            sink.record(&Event::new(Operation::Remove, Some(file), 0));
+        }
+
+        Ok(section)
+    }
+
+    fn write_fragment_loudly(
+        &self,
+        fragment: &Fragment,
+        slug: &str,
+    ) -> Result<PathBuf> {
+        self.write_fragment_with(fragment, slug, StderrSink)
+    }
+
+    fn write_fragment_silently(
+        &self,
+        fragment: &Fragment,
+        slug: &str,
+    ) -> Result<PathBuf> {
+        self.write_fragment_with(fragment, slug, NullSink)
+    }
+
+    fn write_fragment_with<S: Sink>(
+        &self,
+        fragment: &Fragment,
+        slug: &str,
+        mut sink: S,
+    ) -> Result<PathBuf> {
+        let directory = PathBuf::from(self.clone());
+        let error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |e| {
+                Error::new(
+                    Operation::Truncate,
+                    Step::Open,
+                    Some(path),
+                    Cause::Io(e),
+                )
+            }
+        };
+
+        if slug.contains(std::path::is_separator) || slug.contains("..") {
+            let e = error(&directory.join(slug))(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the slug must neither contain path separators nor '..'",
+            ));
+
+            sink.report(&e);
+            return Err(e);
+        }
+
+        std::fs::create_dir_all(&directory)
+            .map_err(error(&directory))
+            .inspect_err(|e| sink.report(e))?;
+
+        let (file, handle) =
+            unused(&directory, &Timestamp::now().compact(), slug)
+                .map_err(error(&directory))
+                .inspect_err(|e| sink.report(e))?;
+
+        write(
+            Operation::Truncate,
+            Some(file.clone()),
+            handle,
+            fragment,
+            Retry::new(),
+            sink,
+        )
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&file);
+        })?;
+
+        Ok(file)
+    }
+}
+
+struct Timestamp {
+    day: u64,
+    hour: u64,
+    minute: u64,
+    month: u64,
+    nanoseconds: u32,
+    second: u64,
+    year: u64,
+}
+
+impl Timestamp {
+    fn compact(&self) -> String {
+        format!(
+            "{:04}{:02}{:02}_{:02}{:02}{:02}",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second
+        )
+    }
+
+    fn now() -> Self {
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let seconds = elapsed.as_secs();
+
+        // Gregorian calendar computation by Howard Hinnant, shifted to years
+        // beginning in March such that leap days end them.
+        let days = seconds / 86_400 + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted = (5 * day_of_year + 2) / 153;
+        let month = if shifted < 10 {
+            shifted + 3
+        } else {
+            shifted - 9
+        };
+
+        Self {
+            day: day_of_year - (153 * shifted + 2) / 5 + 1,
+            hour: seconds % 86_400 / 3600,
+            minute: seconds % 3600 / 60,
+            month,
+            nanoseconds: elapsed.subsec_nanos(),
+            second: seconds % 60,
+            year: era * 400 + year_of_era + u64::from(month <= 2),
+        }
+    }
+
+    fn rfc3339(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanoseconds
+        )
+    }
+}
+
+fn unused(
+    directory: &Path,
+    timestamp: &str,
+    slug: &str,
+) -> std::io::Result<(PathBuf, std::fs::File)> {
+    let mut counter = 0;
+
+    loop {
+        let file = if counter == 0 {
+            directory.join(format!("{timestamp}_{slug}.ron"))
+        } else {
+            directory.join(format!("{timestamp}_{slug}_{counter}.ron"))
+        };
+
+        match std::fs::File::options()
+            .create_new(true)
+            .write(true)
+            .open(&file)
+        {
+            Ok(handle) => return Ok((file, handle)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                counter += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/******************************************************************************/
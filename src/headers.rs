@@ -0,0 +1,508 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    Banner, BannerStyle, Event, Language, NullSink, Operation,
+    PathBufLikeReader, PathBufLikeTruncation, Policy, Result, Sink, StderrSink,
+};
+use std::path::{Path, PathBuf};
+
+/// A license header to verify or to insert.
+///
+/// The template is plain text without any comment markers.  It will be
+/// rendered in the comment syntax of each file, either guessed by
+/// [`crate::Language::from_path`] or set explicitly, and in the configured
+/// [`crate::HeaderStyle`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Header {
+    language: Option<Language>,
+    style: HeaderStyle,
+    template: String,
+}
+
+impl Header {
+    /// Render the header for all files in the given [`crate::Language`].
+    #[must_use]
+    pub const fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Create a new instance from the given template.
+    ///
+    /// Trailing newline characters of the template are ignored.
+    #[must_use]
+    pub fn new(template: &str) -> Self {
+        Self {
+            language: None,
+            style: HeaderStyle::Line,
+            template: template.trim_end_matches('\n').to_string(),
+        }
+    }
+
+    /// Render this header in the comment syntax of the given
+    /// [`crate::Language`].
+    ///
+    /// The rendered header does not end with a newline character.
+    #[must_use]
+    pub fn render(&self, language: Language) -> String {
+        match (&self.style, language) {
+            (HeaderStyle::Boxed { style, title }, _) => {
+                let mut banner = Banner::new(title.as_deref(), &self.template)
+                    .render(&style.language(language));
+
+                banner.pop();
+                banner
+            }
+            (HeaderStyle::Documentation, Language::C | Language::Rust) => {
+                comment(&self.template, "///")
+            }
+            (_, Language::C | Language::Rust) => comment(&self.template, "//"),
+            (_, Language::Hash) => comment(&self.template, "#"),
+            (_, Language::Html) => format!("<!--\n{}\n-->", self.template),
+        }
+    }
+
+    /// Render this header in the given [`crate::HeaderStyle`].
+    #[must_use]
+    pub fn style(mut self, style: HeaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn outdated(&self, text: &str) -> bool {
+        self.template
+            .lines()
+            .find(|line| !line.trim().is_empty())
+            .is_some_and(|line| mask(text).contains(&mask(line.trim())))
+    }
+
+    fn update(
+        &self,
+        path: &Path,
+        content: &str,
+    ) -> (HeaderStatus, Option<String>) {
+        let Some(language) =
+            self.language.or_else(|| Language::from_path(path))
+        else {
+            return (HeaderStatus::Unsupported, None);
+        };
+        let shebang = if content.starts_with("#!") {
+            content.find('\n').map_or(content.len(), |n| n + 1)
+        } else {
+            0
+        };
+        let (head, body) = content.split_at(shebang);
+        let header = self.render(language);
+        let leading = language
+            .blocks(body)
+            .into_iter()
+            .next()
+            .filter(|block| block.begin() == 1);
+
+        match leading {
+            Some(block)
+                if block.text() == header
+                    || block.text().starts_with(&(header.clone() + "\n")) =>
+            {
+                (HeaderStatus::Current, None)
+            }
+            Some(block) if self.outdated(block.text()) => {
+                let end = body
+                    .split_inclusive('\n')
+                    .take(block.end())
+                    .map(str::len)
+                    .sum::<usize>();
+                let ending = if body[..end].ends_with('\n') {
+                    "\n"
+                } else {
+                    ""
+                };
+
+                (
+                    HeaderStatus::Outdated,
+                    Some(head.to_string() + &header + ending + &body[end..]),
+                )
+            }
+            _ => {
+                let separator = if body.is_empty() || body.starts_with('\n') {
+                    ""
+                } else {
+                    "\n"
+                };
+
+                (
+                    HeaderStatus::Missing,
+                    Some(head.to_string() + &header + "\n" + separator + body),
+                )
+            }
+        }
+    }
+}
+
+/// The shape of a rendered license header.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum HeaderStyle {
+    /// A boxed banner, rendered by [`crate::Banner::render`].
+    ///
+    /// The language of the given [`crate::BannerStyle`] is replaced by the one
+    /// of each file.
+    Boxed {
+        /// The shape of the box.
+        style: BannerStyle,
+
+        /// The title to show in the top border, if any.
+        title: Option<String>,
+    },
+
+    /// Documentation comments, such as `///`.
+    ///
+    /// Languages without documentation comments use line comments, instead.
+    Documentation,
+
+    /// Line comments, such as `//` or `#`, where possible.
+    ///
+    /// HTML headers form a single comment block.
+    #[default]
+    Line,
+}
+
+/// The state of the license header of a file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HeaderStatus {
+    /// The file already begins with the header.
+    Current,
+
+    /// The file could not be read or written.
+    ///
+    /// The error has been reported to the [`crate::Sink`].
+    Failed,
+
+    /// The header was missing and has been inserted.
+    Inserted,
+
+    /// The file does not begin with the header.
+    Missing,
+
+    /// The file begins with an outdated version of the header.
+    ///
+    /// A leading comment block is considered an outdated header if it
+    /// contains the first non-empty line of the template, ignoring any
+    /// numbers, such as the copyright year.
+    Outdated,
+
+    /// The header was outdated and has been replaced.
+    Replaced,
+
+    /// The comment syntax of the file could not be determined.
+    Unsupported,
+}
+
+impl HeaderStatus {
+    /// The [`sysexits::ExitCode`] which describes this result best.
+    ///
+    /// Files which begin with the header, either already or after an update,
+    /// result in `Ok` and files which could not be read or written result in
+    /// `IoErr` whereas all others result in `DataErr`.
+    #[must_use]
+    pub const fn exit_code(self) -> sysexits::ExitCode {
+        match self {
+            Self::Current | Self::Inserted | Self::Replaced => {
+                sysexits::ExitCode::Ok
+            }
+            Self::Failed => sysexits::ExitCode::IoErr,
+            Self::Missing | Self::Outdated | Self::Unsupported => {
+                sysexits::ExitCode::DataErr
+            }
+        }
+    }
+}
+
+impl From<HeaderStatus> for sysexits::ExitCode {
+    fn from(status: HeaderStatus) -> Self {
+        status.exit_code()
+    }
+}
+
+/// Verify and insert the license headers of a list of files.
+pub trait HeaderVerifier {
+    /// Check whether the files of this list begin with the given header.
+    ///
+    /// This method behaves just like
+    /// [`crate::HeaderVerifier::check_headers_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_headers_loudly(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>>;
+
+    /// Check whether the files of this list begin with the given header.
+    ///
+    /// Each element of the instance this method is called on needs to be
+    /// convertible to a [`std::path::PathBuf`].  The referenced files will be
+    /// read by [`crate::PathBufLikeReader::read_silently`] and compared with
+    /// the [`crate::Header`] rendered in their comment syntax.  A shebang line
+    /// is skipped.  The files will not be modified.
+    ///
+    /// The return value is either the [`crate::HeaderStatus`] of each file,
+    /// in the order of the list, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  Using [`crate::Policy::Stop`],
+    /// the first file which cannot be read will cause the error to be
+    /// returned.  Using [`crate::Policy::Collect`], such files will be
+    /// reported as [`crate::HeaderStatus::Failed`], instead.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_headers_silently(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>>;
+
+    /// Check whether the files of this list begin with the given header.
+    ///
+    /// This method behaves just like
+    /// [`crate::HeaderVerifier::check_headers_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_headers_with<S: Sink>(
+        &self,
+        header: &Header,
+        policy: Policy,
+        sink: S,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>>;
+
+    /// Make the files of this list begin with the given header.
+    ///
+    /// This method behaves just like
+    /// [`crate::HeaderVerifier::insert_headers_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn insert_headers_loudly(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>>;
+
+    /// Make the files of this list begin with the given header.
+    ///
+    /// This method behaves just like
+    /// [`crate::HeaderVerifier::check_headers_silently`] but inserts missing
+    /// headers and replaces outdated ones by
+    /// [`crate::PathBufLikeTruncation::truncate_atomically_silently`].
+    /// Missing headers are separated from the following content by an empty
+    /// line.  Files which already begin with the header will not be written.
+    /// As each file is replaced atomically, a failure never leaves a file
+    /// with a partially written header.
+    ///
+    /// The return value is either the [`crate::HeaderStatus`] of each file,
+    /// in the order of the list, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  Files with a missing or an
+    /// outdated header are reported as [`crate::HeaderStatus::Inserted`] and
+    /// [`crate::HeaderStatus::Replaced`], respectively.  Using
+    /// [`crate::Policy::Stop`], the first file which cannot be read or
+    /// written will cause the error to be returned while the files before it
+    /// keep their updated headers.  Using [`crate::Policy::Collect`], such
+    /// files will be reported as [`crate::HeaderStatus::Failed`], instead.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn insert_headers_silently(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>>;
+
+    /// Make the files of this list begin with the given header.
+    ///
+    /// This method behaves just like
+    /// [`crate::HeaderVerifier::insert_headers_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn insert_headers_with<S: Sink>(
+        &self,
+        header: &Header,
+        policy: Policy,
+        sink: S,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>>;
+}
+
+impl<P> HeaderVerifier for Vec<P>
+where
+    PathBuf: From<P>,
+    P: Clone,
+{
+    fn check_headers_loudly(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>> {
+        self.check_headers_with(header, policy, StderrSink)
+    }
+
+    fn check_headers_silently(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>> {
+        self.check_headers_with(header, policy, NullSink)
+    }
+
+    fn check_headers_with<S: Sink>(
+        &self,
+        header: &Header,
+        policy: Policy,
+        mut sink: S,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>> {
+        let mut result = Vec::new();
+
+        for element in self {
+            let path = PathBuf::from(element.clone());
+            let status = failed(path.read_with(&mut sink), policy)?.map_or(
+                HeaderStatus::Failed,
+                |content| {
+                    let (status, _) = header.update(&path, &content);
+
+                    skipped(&path, status, &mut sink);
+                    status
+                },
+            );
+
+            result.push((path, status));
+        }
+
+        Ok(result)
+    }
+
+    fn insert_headers_loudly(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>> {
+        self.insert_headers_with(header, policy, StderrSink)
+    }
+
+    fn insert_headers_silently(
+        &self,
+        header: &Header,
+        policy: Policy,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>> {
+        self.insert_headers_with(header, policy, NullSink)
+    }
+
+    fn insert_headers_with<S: Sink>(
+        &self,
+        header: &Header,
+        policy: Policy,
+        mut sink: S,
+    ) -> Result<Vec<(PathBuf, HeaderStatus)>> {
+        let mut result = Vec::new();
+
+        for element in self {
+            let path = PathBuf::from(element.clone());
+            let Some(content) = failed(path.read_with(&mut sink), policy)?
+            else {
+                result.push((path, HeaderStatus::Failed));
+                continue;
+            };
+            let (status, update) = header.update(&path, &content);
+
+            skipped(&path, status, &mut sink);
+
+            let status = match update {
+                Some(update) => failed(
+                    PathBufLikeTruncation::<PathBuf>::truncate_atomically_with(
+                        update,
+                        path.clone(),
+                        &mut sink,
+                    ),
+                    policy,
+                )?
+                .map_or(HeaderStatus::Failed, |()| match status {
+                    HeaderStatus::Missing => HeaderStatus::Inserted,
+                    HeaderStatus::Outdated => HeaderStatus::Replaced,
+                    status => status,
+                }),
+                None => status,
+            };
+
+            result.push((path, status));
+        }
+
+        Ok(result)
+    }
+}
+
+fn comment(template: &str, marker: &str) -> String {
+    template
+        .lines()
+        .map(|line| format!("{marker} {line}").trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn failed<T>(result: Result<T>, policy: Policy) -> Result<Option<T>> {
+    match (result, policy) {
+        (Ok(value), _) => Ok(Some(value)),
+        (Err(_), Policy::Collect) => Ok(None),
+        (Err(e), Policy::Stop) => Err(e),
+    }
+}
+
+fn skipped<S: Sink>(path: &Path, status: HeaderStatus, sink: &mut S) {
+    if status == HeaderStatus::Unsupported {
+        sink.record(&Event::warn(
+            Operation::Read,
+            Some(path.to_path_buf()),
+            "skipped due to an unknown comment syntax".to_string(),
+        ));
+    }
+}
+
+fn mask(text: &str) -> String {
+    text.chars()
+        .zip(std::iter::once(' ').chain(text.chars()))
+        .filter(|(c, previous)| {
+            !(c.is_ascii_digit() && previous.is_ascii_digit())
+        })
+        .map(|(c, _)| if c.is_ascii_digit() { '0' } else { c })
+        .collect()
+}
+
+/******************************************************************************/
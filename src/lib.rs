@@ -127,15 +127,52 @@
     unused_parens,
     unused_variables
 )]
+#![allow(clippy::redundant_pub_crate)]
 
+mod banners;
+mod changelog;
+mod comments;
+mod diagnostics;
+mod diffing;
+mod documentation;
+mod error;
+mod fragments;
+mod headers;
+mod patching;
 mod reading;
+mod regions;
+mod ron;
+mod stdio;
+mod streaming;
+mod versioning;
 mod writing;
 
+pub use banners::{Banner, BannerReader, BannerStyle};
+pub use changelog::{Changelog, ChangelogReader, Fragment, Section};
+pub use comments::{Comment, CommentReader, Language};
+pub use diagnostics::{
+    BufferSink, Event, Format, Level, Logger, NullSink, Sink, StderrSink,
+    WriteSink,
+};
+pub use diffing::Diff;
+pub use documentation::{
+    DocComment, DocLocation, DocumentationReader, DocumentationTruncation,
+};
+pub use error::{Cause, Error, Operation, Result, Step};
+pub use fragments::FragmentDirectory;
+pub use headers::{Header, HeaderStatus, HeaderStyle, HeaderVerifier};
+pub use patching::{Patch, Patcher, Rejection};
 pub use reading::{
-    BufReadReader, OptionReader, PathBufLikeReader, VectorReader,
+    BufReadReader, LineEnding, OptionReader, PathBufLikeReader, VectorReader,
 };
+pub use regions::{Markers, Region, RegionReader, RegionTruncation};
+pub use stdio::StdioPath;
+pub use streaming::{Chunks, Lines};
+pub use versioning::{Bump, Version, VersionSynchroniser};
 pub use writing::{
-    OptionTruncation, PathBufLikeAppendix, PathBufLikeTruncation, Writer,
+    Bytes, Check, OptionAppendix, OptionTruncation, PathBufLikeAppendix,
+    PathBufLikeTruncation, Payload, Policy, Retry, VectorAppendix,
+    VectorTruncation, Writer,
 };
 
 /// This crate's name.
@@ -0,0 +1,568 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    writing::{text, Staged},
+    Cause, Error, Event, NullSink, Operation, PathBufLikeReader, Payload,
+    Result, Sink, StderrSink, Step,
+};
+use std::path::{Path, PathBuf};
+
+/// The settings for applying unified diffs.
+///
+/// By default, the first component of each path is stripped, just as `patch
+/// -p1` does, such that the `a/` and `b/` labels of `git diff` and
+/// [`crate::Diff`] are removed.  The hunks need to match exactly and accepted
+/// hunks are applied even if others are rejected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Patch {
+    all_or_nothing: bool,
+    fuzz: usize,
+    strip: usize,
+}
+
+impl Patch {
+    /// Set whether to leave all files untouched if any hunk is rejected.
+    #[must_use]
+    pub const fn all_or_nothing(mut self, all_or_nothing: bool) -> Self {
+        self.all_or_nothing = all_or_nothing;
+        self
+    }
+
+    /// Set the number of leading and trailing context lines which may be
+    /// ignored in order to match a hunk.
+    #[must_use]
+    pub const fn fuzz(mut self, lines: usize) -> Self {
+        self.fuzz = lines;
+        self
+    }
+
+    /// Create a new instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            all_or_nothing: false,
+            fuzz: 0,
+            strip: 1,
+        }
+    }
+
+    /// Set the number of leading path components to strip.
+    #[must_use]
+    pub const fn strip(mut self, components: usize) -> Self {
+        self.strip = components;
+        self
+    }
+
+    fn path(&self, raw: &str) -> PathBuf {
+        Path::new(raw).components().skip(self.strip).collect()
+    }
+}
+
+impl Default for Patch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Apply unified diffs to the files they describe.
+pub trait Patcher {
+    /// Apply the unified diff this method is called on.
+    ///
+    /// This method behaves just like [`crate::Patcher::patch_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn patch_loudly(self, settings: Patch) -> Result<Vec<Rejection>>;
+
+    /// Apply the unified diff this method is called on.
+    ///
+    /// The data this method is called on will be interpreted as UTF-8 text,
+    /// namely as unified diff.  The data therefore needs to implement
+    /// [`crate::Payload`].  The files it describes will be read by
+    /// [`crate::PathBufLikeReader::read_silently`].  Each hunk will be
+    /// searched for near the position given in its header, taking the shifts
+    /// by previous hunks into account.  If a hunk cannot be found, the fuzz of
+    /// the given [`crate::Patch`] will be applied.  Afterwards, the files will
+    /// be written to temporary files next to them.  Only if all of them could
+    /// be written, files whose modified path is `/dev/null` will be removed
+    /// and the temporary files will be renamed to the patched files, just as
+    /// [`crate::PathBufLikeTruncation::truncate_atomically_silently`] does.
+    /// Missing parent directories of new files will be created, just like
+    /// `patch(1)` does.
+    ///
+    /// The return value is either the list of [`crate::Rejection`]s, in case
+    /// of success, or a [`crate::Error`] to describe the error cause,
+    /// otherwise.  An empty list means that the diff was applied completely.
+    /// In all-or-nothing mode, no file will be written unless the list is
+    /// empty.  Malformed diffs, including invalid UTF-8, unreadable files and
+    /// failed writes are reported before any file is modified.  A failed
+    /// removal is reported before any file is replaced.  Only failures while
+    /// removing or renaming files can leave some of them modified.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn patch_silently(self, settings: Patch) -> Result<Vec<Rejection>>;
+
+    /// Apply the unified diff this method is called on.
+    ///
+    /// This method behaves just like [`crate::Patcher::patch_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn patch_with<S: Sink>(
+        self,
+        settings: Patch,
+        sink: S,
+    ) -> Result<Vec<Rejection>>;
+}
+
+impl<T: Payload> Patcher for T {
+    fn patch_loudly(self, settings: Patch) -> Result<Vec<Rejection>> {
+        self.patch_with(settings, StderrSink)
+    }
+
+    fn patch_silently(self, settings: Patch) -> Result<Vec<Rejection>> {
+        self.patch_with(settings, NullSink)
+    }
+
+    fn patch_with<S: Sink>(
+        self,
+        settings: Patch,
+        mut sink: S,
+    ) -> Result<Vec<Rejection>> {
+        let files = text(&self, Operation::Patch)
+            .and_then(|diff| parse(&diff))
+            .inspect_err(|e| sink.report(e))?;
+        let mut rejections = Vec::new();
+        let mut results = Vec::new();
+
+        for file in &files {
+            if let Some(result) =
+                file.apply(settings, &mut rejections, &mut sink)?
+            {
+                results.push(result);
+            }
+        }
+
+        if settings.all_or_nothing && !rejections.is_empty() {
+            return Ok(rejections);
+        }
+
+        let mut removals = Vec::new();
+        let mut staged = Vec::new();
+
+        for (path, content) in results {
+            match content {
+                Some(content) => staged.push(
+                    parents(&path)
+                        .and_then(|()| Staged::new(&path, &content))
+                        .inspect_err(|e| sink.report(e))?,
+                ),
+                None => removals.push(path),
+            }
+        }
+
+        for path in removals {
+            std::fs::remove_file(&path)
+                .map_err(|e| {
+                    Error::new(
+                        Operation::Remove,
+                        Step::Remove,
+                        Some(path.clone()),
+                        Cause::Io(e),
+                    )
+                })
+                .inspect_err(|e| sink.report(e))?;
+            sink.record(&Event::new(Operation::Remove, Some(path), 0));
+        }
+
+        for file in staged {
+            let path = file.path().to_path_buf();
+            let written = file.commit().inspect_err(|e| sink.report(e))?;
+
+            sink.record(&Event::new(Operation::Truncate, Some(path), written));
+        }
+
+        Ok(rejections)
+    }
+}
+
+/// A hunk which could not be applied.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rejection {
+    hunk: usize,
+    path: PathBuf,
+    text: String,
+}
+
+impl Rejection {
+    /// The position of the hunk among the ones for the same file, starting at
+    /// one.
+    #[must_use]
+    pub const fn hunk(&self) -> usize {
+        self.hunk
+    }
+
+    /// The file the hunk should have been applied to.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The hunk as it was given in the unified diff, including its header.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl std::fmt::Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Hunk #{} could not be applied to '{}'.",
+            self.hunk,
+            self.path.display()
+        )
+    }
+}
+
+struct File {
+    hunks: Vec<Hunk>,
+    modified: Option<String>,
+    original: Option<String>,
+}
+
+impl File {
+    fn apply<S: Sink>(
+        &self,
+        settings: Patch,
+        rejections: &mut Vec<Rejection>,
+        mut sink: S,
+    ) -> Result<Option<(PathBuf, Option<String>)>> {
+        let Some(path) = self
+            .modified
+            .as_deref()
+            .or(self.original.as_deref())
+            .map(|p| settings.path(p))
+        else {
+            return Ok(None);
+        };
+
+        let original = match &self.original {
+            Some(p) => settings.path(p).read_with(&mut sink)?,
+            None => String::new(),
+        };
+
+        let mut lines: Vec<&str> = original.split_inclusive('\n').collect();
+        let (mut forward, mut backward, mut floor) = (0, 0, 0);
+        let mut applied = false;
+
+        for (index, hunk) in self.hunks.iter().enumerate() {
+            let expected = (hunk.start() + forward).saturating_sub(backward);
+
+            match (0..=settings.fuzz)
+                .find_map(|fuzz| hunk.locate(&lines, expected, floor, fuzz))
+            {
+                Some((position, fuzz)) => {
+                    let (old, new) = hunk.sides(fuzz);
+                    let expected = expected + fuzz.0;
+
+                    if position >= expected {
+                        forward += position - expected;
+                    } else {
+                        backward += expected - position;
+                    }
+
+                    forward += new.len();
+                    backward += old.len();
+                    floor = position + new.len();
+                    lines.splice(position..position + old.len(), new);
+                    applied = true;
+                }
+                None => rejections.push(Rejection {
+                    hunk: index + 1,
+                    path: path.clone(),
+                    text: hunk.text.clone(),
+                }),
+            }
+        }
+
+        let content = lines.concat();
+
+        Ok(if self.modified.is_none() && content.is_empty() {
+            Some((path, None))
+        } else if applied || self.original.is_none() {
+            Some((path, Some(content)))
+        } else {
+            None
+        })
+    }
+}
+
+struct Hunk {
+    count: usize,
+    lines: Vec<(Kind, String)>,
+    start: usize,
+    text: String,
+}
+
+impl Hunk {
+    fn locate(
+        &self,
+        lines: &[&str],
+        expected: usize,
+        floor: usize,
+        fuzz: usize,
+    ) -> Option<(usize, (usize, usize))> {
+        let leading = self
+            .lines
+            .iter()
+            .take_while(|(kind, _)| *kind == Kind::Context)
+            .count()
+            .min(fuzz);
+        let trailing = self
+            .lines
+            .iter()
+            .rev()
+            .take_while(|(kind, _)| *kind == Kind::Context)
+            .count()
+            .min(fuzz);
+
+        let (old, _) = self.sides((leading, trailing));
+        let last = lines.len().checked_sub(old.len())?;
+        let expected = expected + leading;
+
+        (0..=lines.len())
+            .flat_map(|distance| {
+                [
+                    expected.checked_sub(distance),
+                    expected.checked_add(distance),
+                ]
+            })
+            .flatten()
+            .filter(|position| (floor..=last).contains(position))
+            .find(|&position| lines[position..position + old.len()] == old)
+            .map(|position| (position, (leading, trailing)))
+    }
+
+    fn sides(
+        &self,
+        (leading, trailing): (usize, usize),
+    ) -> (Vec<&str>, Vec<&str>) {
+        let lines = &self.lines[leading..self.lines.len() - trailing];
+        let side = |excluded| {
+            lines
+                .iter()
+                .filter(|(kind, _)| *kind != excluded)
+                .map(|(_, line)| line.as_str())
+                .collect()
+        };
+
+        (side(Kind::Insert), side(Kind::Remove))
+    }
+
+    const fn start(&self) -> usize {
+        if self.count == 0 {
+            self.start
+        } else {
+            self.start - 1
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Context,
+    Insert,
+    Remove,
+}
+
+fn header(line: &str) -> Option<String> {
+    let path = line
+        .trim_end_matches(['\n', '\r'])
+        .split('\t')
+        .next()
+        .unwrap_or_default();
+
+    (path != "/dev/null").then(|| path.to_string())
+}
+
+fn hunk(lines: &[&str], index: usize) -> Result<(Hunk, usize)> {
+    let malformed = || syntax(index + 1, 1, "malformed hunk header");
+    let (old, new) = lines[index]
+        .strip_prefix("@@ -")
+        .and_then(|rest| rest.split_once(" @@"))
+        .and_then(|(ranges, _)| ranges.split_once(" +"))
+        .ok_or_else(malformed)?;
+    let (start, count) = range(old)
+        .filter(|&(start, count)| start > 0 || count == 0)
+        .ok_or_else(malformed)?;
+    let (_, new_count) = range(new).ok_or_else(malformed)?;
+    let (mut remaining_old, mut remaining_new) = (count, new_count);
+    let mut hunk_lines: Vec<(Kind, String)> = Vec::new();
+    let mut next = index + 1;
+
+    while remaining_old > 0 || remaining_new > 0 {
+        let line = lines
+            .get(next)
+            .ok_or_else(|| syntax(next + 1, 1, "unexpected end of hunk"))?;
+        let (kind, text) = match line.as_bytes().first() {
+            Some(b' ') => (Some(Kind::Context), &line[1..]),
+            Some(b'\n' | b'\r') => (Some(Kind::Context), *line),
+            Some(b'+') => (Some(Kind::Insert), &line[1..]),
+            Some(b'-') => (Some(Kind::Remove), &line[1..]),
+            Some(b'\\') => (None, ""),
+            _ => return Err(syntax(next + 1, 1, "unexpected line in hunk")),
+        };
+
+        match kind {
+            Some(kind) => {
+                let (old, new) = match kind {
+                    Kind::Context => (1, 1),
+                    Kind::Insert => (0, 1),
+                    Kind::Remove => (1, 0),
+                };
+
+                if remaining_old < old || remaining_new < new {
+                    return Err(syntax(
+                        next + 1,
+                        1,
+                        "hunk exceeds the announced length",
+                    ));
+                }
+
+                remaining_old -= old;
+                remaining_new -= new;
+                hunk_lines.push((kind, text.to_string()));
+            }
+            None => strip_newline(&mut hunk_lines),
+        }
+
+        next += 1;
+    }
+
+    if lines.get(next).is_some_and(|line| line.starts_with('\\')) {
+        strip_newline(&mut hunk_lines);
+        next += 1;
+    }
+
+    Ok((
+        Hunk {
+            count,
+            lines: hunk_lines,
+            start,
+            text: lines[index..next].concat(),
+        },
+        next,
+    ))
+}
+
+fn parse(diff: &str) -> Result<Vec<File>> {
+    let lines: Vec<&str> = diff.split_inclusive('\n').collect();
+    let mut files = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        if let Some(original) = lines[index].strip_prefix("--- ") {
+            let modified = lines
+                .get(index + 1)
+                .and_then(|line| line.strip_prefix("+++ "))
+                .ok_or_else(|| {
+                    syntax(index + 2, 1, "missing header of the modified file")
+                })?;
+
+            files.push(File {
+                hunks: Vec::new(),
+                modified: header(modified),
+                original: header(original),
+            });
+            index += 2;
+        } else if lines[index].starts_with("@@ ") {
+            let (hunk, next) = hunk(&lines, index)?;
+
+            files
+                .last_mut()
+                .ok_or_else(|| {
+                    syntax(index + 1, 1, "hunk without file header")
+                })?
+                .hunks
+                .push(hunk);
+            index = next;
+        } else {
+            index += 1;
+        }
+    }
+
+    Ok(files)
+}
+
+fn parents(path: &Path) -> Result<()> {
+    path.parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .map_err(|e| {
+            Error::new(
+                Operation::Patch,
+                Step::Open,
+                Some(path.to_path_buf()),
+                Cause::Io(e),
+            )
+        })
+}
+
+fn range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, count)) => {
+            Some((start.parse().ok()?, count.parse().ok()?))
+        }
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+fn strip_newline(lines: &mut [(Kind, String)]) {
+    if let Some((_, line)) = lines.last_mut() {
+        if line.ends_with('\n') {
+            line.pop();
+        }
+    }
+}
+
+fn syntax(line: usize, column: usize, reason: &str) -> Error {
+    Error::new(
+        Operation::Patch,
+        Step::Parse,
+        None,
+        Cause::Parse {
+            line,
+            column,
+            reason: reason.to_string(),
+        },
+    )
+}
+
+/******************************************************************************/
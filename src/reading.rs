@@ -17,11 +17,184 @@
 |                                                                              |
 \******************************************************************************/
 
-use std::path::PathBuf;
-use sysexits::Result;
+use crate::{
+    streaming::{Chunks, Lines, Source},
+    Cause, Error, Event, NullSink, Operation, Result, Sink, StderrSink, Step,
+};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
 
 /// Read from a [`std::io::BufRead`]er.
 pub trait BufReadReader {
+    /// Stream fixed-size chunks from a [`std::io::BufRead`]er.
+    ///
+    /// This method behaves just like
+    /// [`crate::BufReadReader::chunks_silently`] despite also printing error
+    /// messages to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_loudly<'a>(self, size: usize) -> Chunks<'a>
+    where
+        Self: 'a;
+
+    /// Stream fixed-size chunks from a [`std::io::BufRead`]er.
+    ///
+    /// The instance this method is called on needs to implement
+    /// [`std::io::BufRead`] and will be consumed in order to retrieve its
+    /// content.  The content will be read lazily in chunks of the given size.
+    ///
+    /// The return value is an iterator whose items are either the next chunk,
+    /// in case of success, or a [`crate::Error`] to describe the error
+    /// cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_silently<'a>(self, size: usize) -> Chunks<'a>
+    where
+        Self: 'a;
+
+    /// Stream fixed-size chunks from a [`std::io::BufRead`]er.
+    ///
+    /// This method behaves just like [`crate::BufReadReader::chunks_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_with<'a, S: Sink + 'a>(self, size: usize, sink: S) -> Chunks<'a>
+    where
+        Self: 'a;
+
+    /// Stream the lines of a [`std::io::BufRead`]er.
+    ///
+    /// This method behaves just like [`crate::BufReadReader::lines_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    fn lines_loudly<'a>(self) -> Lines<'a>
+    where
+        Self: 'a;
+
+    /// Stream the lines of a [`std::io::BufRead`]er.
+    ///
+    /// The instance this method is called on needs to implement
+    /// [`std::io::BufRead`] and will be consumed in order to retrieve its
+    /// content.  The content will be read lazily line by line.
+    ///
+    /// The return value is an iterator whose items are either the next line
+    /// without its line ending, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    fn lines_silently<'a>(self) -> Lines<'a>
+    where
+        Self: 'a;
+
+    /// Stream the lines of a [`std::io::BufRead`]er.
+    ///
+    /// This method behaves just like [`crate::BufReadReader::lines_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    fn lines_with<'a, S: Sink + 'a>(self, sink: S) -> Lines<'a>
+    where
+        Self: 'a;
+
+    /// Read bytes from a [`std::io::BufRead`]er and print error messages.
+    ///
+    /// This method behaves just like
+    /// [`crate::BufReadReader::read_bytes_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_loudly(self) -> Result<Vec<u8>>;
+
+    /// Read bytes from a [`std::io::BufRead`]er and suppress any error
+    /// messages.
+    ///
+    /// The instance this method is called on needs to implement
+    /// [`std::io::BufRead`] and will be consumed in order to retrieve its
+    /// content.  The content will be read as it is, without any assumptions
+    /// about its encoding or line structure.
+    ///
+    /// The return value is either the read content as a [`Vec<u8>`], in case
+    /// of success, or a [`crate::Error`] to describe the error cause,
+    /// otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_silently(self) -> Result<Vec<u8>>;
+
+    /// Read bytes from a [`std::io::BufRead`]er and report any error messages.
+    ///
+    /// This method behaves just like
+    /// [`crate::BufReadReader::read_bytes_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_with<S: Sink>(self, sink: S) -> Result<Vec<u8>>;
+
+    /// Read from a [`std::io::BufRead`]er and print error messages.
+    ///
+    /// This method behaves just like
+    /// [`crate::BufReadReader::read_normalised_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_normalised_loudly(self, line_ending: LineEnding) -> Result<String>;
+
+    /// Read from a [`std::io::BufRead`]er and suppress any error messages.
+    ///
+    /// The instance this method is called on needs to implement
+    /// [`std::io::BufRead`] and will be consumed in order to retrieve its
+    /// content.  In contrast to [`crate::BufReadReader::read_silently`], the
+    /// content is not split into lines.  Its line endings will be treated as
+    /// requested by the given [`crate::LineEnding`].  A missing final newline
+    /// will not be added in any case.  Hence, [`crate::LineEnding::Preserve`]
+    /// yields exactly the same content as [`crate::PathBufLikeReader`] does
+    /// for files.
+    ///
+    /// The return value is either the read content as a [`String`], in case of
+    /// success, or a [`crate::Error`] to describe the error cause,
+    /// otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_normalised_silently(
+        self,
+        line_ending: LineEnding,
+    ) -> Result<String>;
+
+    /// Read from a [`std::io::BufRead`]er and report any error messages.
+    ///
+    /// This method behaves just like
+    /// [`crate::BufReadReader::read_normalised_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_normalised_with<S: Sink>(
+        self,
+        line_ending: LineEnding,
+        sink: S,
+    ) -> Result<String>;
+
     /// Read from a [`std::io::BufRead`]er and print error messages.
     ///
     /// This method behaves just like
@@ -30,7 +203,7 @@ pub trait BufReadReader {
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_loudly(self) -> Result<String>;
 
     /// Read from a [`std::io::BufRead`]er and suppress any error messages.
@@ -42,55 +215,323 @@ pub trait BufReadReader {
     /// trailing newline character.
     ///
     /// The return value is either the read content as a [`String`], in case of
-    /// success, or a [`sysexits::ExitCode`] to describe the error cause,
+    /// success, or a [`crate::Error`] to describe the error cause,
     /// otherwise.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_silently(self) -> Result<String>;
+
+    /// Read from a [`std::io::BufRead`]er and report any error messages.
+    ///
+    /// This method behaves just like [`crate::BufReadReader::read_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_with<S: Sink>(self, sink: S) -> Result<String>;
 }
 
 impl<T: std::io::BufRead> BufReadReader for T {
-    fn read_loudly(self) -> Result<String> {
-        let mut result = String::new();
+    fn chunks_loudly<'a>(self, size: usize) -> Chunks<'a>
+    where
+        Self: 'a,
+    {
+        self.chunks_with(size, StderrSink)
+    }
 
-        for line in self.lines() {
-            match line {
-                Ok(l) => {
-                    result.push_str(&l);
-                    result.push('\n');
-                    Ok(())
-                }
-                Err(e) => {
-                    eprintln!("{e}");
-                    Err(e)
-                }
-            }?;
-        }
+    fn chunks_silently<'a>(self, size: usize) -> Chunks<'a>
+    where
+        Self: 'a,
+    {
+        self.chunks_with(size, NullSink)
+    }
+
+    fn chunks_with<'a, S: Sink + 'a>(self, size: usize, sink: S) -> Chunks<'a>
+    where
+        Self: 'a,
+    {
+        Chunks::new(vec![Source::Reader(Box::new(self))].into(), size, sink)
+    }
 
+    fn lines_loudly<'a>(self) -> Lines<'a>
+    where
+        Self: 'a,
+    {
+        self.lines_with(StderrSink)
+    }
+
+    fn lines_silently<'a>(self) -> Lines<'a>
+    where
+        Self: 'a,
+    {
+        self.lines_with(NullSink)
+    }
+
+    fn lines_with<'a, S: Sink + 'a>(self, sink: S) -> Lines<'a>
+    where
+        Self: 'a,
+    {
+        Lines::new(vec![Source::Reader(Box::new(self))].into(), sink)
+    }
+
+    fn read_bytes_loudly(self) -> Result<Vec<u8>> {
+        self.read_bytes_with(StderrSink)
+    }
+
+    fn read_bytes_silently(self) -> Result<Vec<u8>> {
+        self.read_bytes_with(NullSink)
+    }
+
+    fn read_bytes_with<S: Sink>(mut self, mut sink: S) -> Result<Vec<u8>> {
+        let mut result = Vec::new();
+
+        self.read_to_end(&mut result)
+            .map_err(reading(Step::Read, None))
+            .inspect_err(|e| sink.report(e))?;
+
+        sink.record(&Event::new(Operation::Read, None, result.len()));
         Ok(result)
     }
 
+    fn read_normalised_loudly(self, line_ending: LineEnding) -> Result<String> {
+        self.read_normalised_with(line_ending, StderrSink)
+    }
+
+    fn read_normalised_silently(
+        self,
+        line_ending: LineEnding,
+    ) -> Result<String> {
+        self.read_normalised_with(line_ending, NullSink)
+    }
+
+    fn read_normalised_with<S: Sink>(
+        mut self,
+        line_ending: LineEnding,
+        mut sink: S,
+    ) -> Result<String> {
+        let mut result = String::new();
+
+        self.read_to_string(&mut result)
+            .map_err(reading(Step::Read, None))
+            .inspect_err(|e| sink.report(e))?;
+
+        sink.record(&Event::new(Operation::Read, None, result.len()));
+        Ok(line_ending.apply(result))
+    }
+
+    fn read_loudly(self) -> Result<String> {
+        BufReadReader::read_with(self, StderrSink)
+    }
+
     fn read_silently(self) -> Result<String> {
+        BufReadReader::read_with(self, NullSink)
+    }
+
+    fn read_with<S: Sink>(self, mut sink: S) -> Result<String> {
         let mut result = String::new();
 
         for line in self.lines() {
-            result.push_str(&line?);
+            result.push_str(
+                &line
+                    .map_err(reading(Step::Read, None))
+                    .inspect_err(|e| sink.report(e))?,
+            );
             result.push('\n');
         }
 
+        sink.record(&Event::new(Operation::Read, None, result.len()));
         Ok(result)
     }
 }
 
+/// The line endings to apply when reading.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LineEnding {
+    /// Convert every `\r\n` into `\n`.
+    ///
+    /// Stray carriage returns, which are not followed by a line feed, are kept.
+    Lf,
+
+    /// Convert every `\n`, which is not preceded by `\r`, into `\r\n`.
+    CrLf,
+
+    /// Keep the content exactly as it is.
+    #[default]
+    Preserve,
+}
+
+impl LineEnding {
+    fn apply(self, content: String) -> String {
+        match self {
+            Self::CrLf => {
+                let mut result = String::with_capacity(content.len());
+                let mut previous = None;
+
+                for c in content.chars() {
+                    if c == '\n' && previous != Some('\r') {
+                        result.push('\r');
+                    }
+
+                    result.push(c);
+                    previous = Some(c);
+                }
+
+                result
+            }
+            Self::Lf => content.replace("\r\n", "\n"),
+            Self::Preserve => content,
+        }
+    }
+}
+
 /// Read from either source, dependending on the circumstances.
 pub trait OptionReader<B>
 where
     B: BufReadReader,
 {
+    /// Stream fixed-size chunks from this instance or the given alternative.
+    ///
+    /// This method behaves just like
+    /// [`crate::OptionReader::chunks_silently`] despite also printing error
+    /// messages to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_loudly<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a;
+
+    /// Stream fixed-size chunks from this instance or the given alternative.
+    ///
+    /// If the instance this method is called has [`Some`] value, the contained
+    /// value will be interpreted as file to read from.  Therefore, the value
+    /// needs to implement [`crate::PathBufLikeReader`].  In case the instance
+    /// this method is called on is [`None`], the given alternative will be
+    /// considered the source to read from.  Therefore, the alternative needs to
+    /// implement [`crate::BufReadReader`].  The content will be read lazily in
+    /// chunks of the given size.
+    ///
+    /// The return value is an iterator whose items are either the next chunk,
+    /// in case of success, or a [`crate::Error`] to describe the error
+    /// cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_silently<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a;
+
+    /// Stream fixed-size chunks from this instance or the given alternative.
+    ///
+    /// This method behaves just like [`crate::OptionReader::chunks_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_with<'a, S: Sink + 'a>(
+        &self,
+        alternative: B,
+        size: usize,
+        sink: S,
+    ) -> Chunks<'a>
+    where
+        B: 'a;
+
+    /// Stream the lines of this instance or the given alternative.
+    ///
+    /// This method behaves just like [`crate::OptionReader::lines_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    fn lines_loudly<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a;
+
+    /// Stream the lines of this instance or the given alternative.
+    ///
+    /// If the instance this method is called has [`Some`] value, the contained
+    /// value will be interpreted as file to read from.  Therefore, the value
+    /// needs to implement [`crate::PathBufLikeReader`].  In case the instance
+    /// this method is called on is [`None`], the given alternative will be
+    /// considered the source to read from.  Therefore, the alternative needs to
+    /// implement [`crate::BufReadReader`].  The content will be read lazily
+    /// line by line.
+    ///
+    /// The return value is an iterator whose items are either the next line
+    /// without its line ending, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    fn lines_silently<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a;
+
+    /// Stream the lines of this instance or the given alternative.
+    ///
+    /// This method behaves just like [`crate::OptionReader::lines_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    fn lines_with<'a, S: Sink + 'a>(
+        &self,
+        alternative: B,
+        sink: S,
+    ) -> Lines<'a>
+    where
+        B: 'a;
+
+    /// Read bytes from this instance or the given alternative.
+    ///
+    /// This method behaves just like
+    /// [`crate::OptionReader::read_bytes_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_loudly(&self, alternative: B) -> Result<Vec<u8>>;
+
+    /// Read bytes from this instance or the given alternative.
+    ///
+    /// If the instance this method is called has [`Some`] value, the contained
+    /// value will be interpreted as file to read from.  Therefore, the value
+    /// needs to implement [`crate::PathBufLikeReader`].  In case the instance
+    /// this method is called on is [`None`], the given alternative will be
+    /// considered the source to read from.  Therefore, the alternative needs to
+    /// implement [`crate::BufReadReader`].
+    ///
+    /// The return value is either the read content as a [`Vec<u8>`], in case
+    /// of success, or a [`crate::Error`] to describe the error cause,
+    /// otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_silently(&self, alternative: B) -> Result<Vec<u8>>;
+
+    /// Read bytes from this instance or the given alternative.
+    ///
+    /// This method behaves just like
+    /// [`crate::OptionReader::read_bytes_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_with<S: Sink>(
+        &self,
+        alternative: B,
+        sink: S,
+    ) -> Result<Vec<u8>>;
+
     /// Read from this instance or the given alternative.
     ///
     /// This method behaves just like
@@ -99,7 +540,7 @@ where
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_loudly(&self, alternative: B) -> Result<String>;
 
     /// Read from this instance or the given alternative.
@@ -112,35 +553,228 @@ where
     /// implement [`crate::BufReadReader`].
     ///
     /// The return value is either the read content as a [`String`], in case of
-    /// success, or a [`sysexits::ExitCode`] to describe the error cause,
+    /// success, or a [`crate::Error`] to describe the error cause,
     /// otherwise.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_silently(&self, alternative: B) -> Result<String>;
+
+    /// Read from this instance or the given alternative.
+    ///
+    /// This method behaves just like [`crate::OptionReader::read_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_with<S: Sink>(&self, alternative: B, sink: S) -> Result<String>;
 }
 
 impl<B: std::io::BufRead, P: PathBufLikeReader> OptionReader<B> for Option<P> {
+    fn chunks_loudly<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a,
+    {
+        self.chunks_with(alternative, size, StderrSink)
+    }
+
+    fn chunks_silently<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a,
+    {
+        self.chunks_with(alternative, size, NullSink)
+    }
+
+    fn chunks_with<'a, S: Sink + 'a>(
+        &self,
+        alternative: B,
+        size: usize,
+        sink: S,
+    ) -> Chunks<'a>
+    where
+        B: 'a,
+    {
+        match self {
+            Some(p) => p.chunks_with(size, sink),
+            None => alternative.chunks_with(size, sink),
+        }
+    }
+
+    fn lines_loudly<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a,
+    {
+        self.lines_with(alternative, StderrSink)
+    }
+
+    fn lines_silently<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a,
+    {
+        self.lines_with(alternative, NullSink)
+    }
+
+    fn lines_with<'a, S: Sink + 'a>(&self, alternative: B, sink: S) -> Lines<'a>
+    where
+        B: 'a,
+    {
+        match self {
+            Some(p) => p.lines_with(sink),
+            None => alternative.lines_with(sink),
+        }
+    }
+
+    fn read_bytes_loudly(&self, alternative: B) -> Result<Vec<u8>> {
+        self.read_bytes_with(alternative, StderrSink)
+    }
+
+    fn read_bytes_silently(&self, alternative: B) -> Result<Vec<u8>> {
+        self.read_bytes_with(alternative, NullSink)
+    }
+
+    fn read_bytes_with<S: Sink>(
+        &self,
+        alternative: B,
+        sink: S,
+    ) -> Result<Vec<u8>> {
+        match self {
+            Some(p) => p.read_bytes_with(sink),
+            None => alternative.read_bytes_with(sink),
+        }
+    }
+
     fn read_loudly(&self, alternative: B) -> Result<String> {
-        self.as_ref().map_or_else(
-            || alternative.read_loudly(),
-            PathBufLikeReader::read_loudly,
-        )
+        self.read_with(alternative, StderrSink)
     }
 
     fn read_silently(&self, alternative: B) -> Result<String> {
-        self.as_ref().map_or_else(
-            || alternative.read_silently(),
-            PathBufLikeReader::read_silently,
-        )
+        self.read_with(alternative, NullSink)
+    }
+
+    fn read_with<S: Sink>(&self, alternative: B, sink: S) -> Result<String> {
+        match self {
+            Some(p) => p.read_with(sink),
+            None => alternative.read_with(sink),
+        }
     }
 }
 
 /// Read from files given as instances convertible to a [`std::path::PathBuf`].
+///
+/// A [`crate::StdioPath`] which is `-` denotes [`std::io::Stdin`], instead.
 pub trait PathBufLikeReader {
+    /// Stream fixed-size chunks from the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeReader::chunks_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_loudly(&self, size: usize) -> Chunks<'static>;
+
+    /// Stream fixed-size chunks from the file this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be opened as soon as
+    /// the first chunk is requested and read lazily in chunks of the given
+    /// size.
+    ///
+    /// The return value is an iterator whose items are either the next chunk,
+    /// in case of success, or a [`crate::Error`] to describe the error
+    /// cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_silently(&self, size: usize) -> Chunks<'static>;
+
+    /// Stream fixed-size chunks from the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeReader::chunks_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_with<'a, S: Sink + 'a>(&self, size: usize, sink: S)
+        -> Chunks<'a>;
+
+    /// Stream the lines of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeReader::lines_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    fn lines_loudly(&self) -> Lines<'static>;
+
+    /// Stream the lines of the file this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be opened as soon as
+    /// the first line is requested and read lazily line by line.
+    ///
+    /// The return value is an iterator whose items are either the next line
+    /// without its line ending, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    fn lines_silently(&self) -> Lines<'static>;
+
+    /// Stream the lines of the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeReader::lines_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    fn lines_with<'a, S: Sink + 'a>(&self, sink: S) -> Lines<'a>;
+
+    /// Read bytes from the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeReader::read_bytes_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_loudly(&self) -> Result<Vec<u8>>;
+
+    /// Read bytes from the file this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be opened and read.
+    /// In contrast to [`crate::PathBufLikeReader::read_silently`], the content
+    /// is not required to be valid UTF-8.
+    ///
+    /// The return value is either the read content as a [`Vec<u8>`], in case
+    /// of success, or a [`crate::Error`] to describe the error cause,
+    /// otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_silently(&self) -> Result<Vec<u8>>;
+
+    /// Read bytes from the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeReader::read_bytes_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_with<S: Sink>(&self, sink: S) -> Result<Vec<u8>>;
+
     /// Read from the file this method is called on.
     ///
     /// This method behaves just like
@@ -149,7 +783,7 @@ pub trait PathBufLikeReader {
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_loudly(&self) -> Result<String>;
 
     /// Read from the file this method is called on.
@@ -158,15 +792,26 @@ pub trait PathBufLikeReader {
     /// [`std::path::PathBuf`].  The referenced file will be opened and read.
     ///
     /// The return value is either the read content as a [`String`], in case of
-    /// success, or a [`sysexits::ExitCode`] to describe the error cause,
+    /// success, or a [`crate::Error`] to describe the error cause,
     /// otherwise.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_silently(&self) -> Result<String>;
+
+    /// Read from the file this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeReader::read_silently`] despite also reporting error
+    /// messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_with<S: Sink>(&self, sink: S) -> Result<String>;
 }
 
 impl<T> PathBufLikeReader for T
@@ -174,21 +819,84 @@ where
     PathBuf: From<T>,
     T: Clone,
 {
+    fn chunks_loudly(&self, size: usize) -> Chunks<'static> {
+        self.chunks_with(size, StderrSink)
+    }
+
+    fn chunks_silently(&self, size: usize) -> Chunks<'static> {
+        self.chunks_with(size, NullSink)
+    }
+
+    fn chunks_with<'a, S: Sink + 'a>(
+        &self,
+        size: usize,
+        sink: S,
+    ) -> Chunks<'a> {
+        Chunks::new(
+            vec![Source::Path(PathBuf::from(self.clone()))].into(),
+            size,
+            sink,
+        )
+    }
+
+    fn lines_loudly(&self) -> Lines<'static> {
+        self.lines_with(StderrSink)
+    }
+
+    fn lines_silently(&self) -> Lines<'static> {
+        self.lines_with(NullSink)
+    }
+
+    fn lines_with<'a, S: Sink + 'a>(&self, sink: S) -> Lines<'a> {
+        Lines::new(vec![Source::Path(PathBuf::from(self.clone()))].into(), sink)
+    }
+
+    fn read_bytes_loudly(&self) -> Result<Vec<u8>> {
+        self.read_bytes_with(StderrSink)
+    }
+
+    fn read_bytes_silently(&self) -> Result<Vec<u8>> {
+        self.read_bytes_with(NullSink)
+    }
+
+    fn read_bytes_with<S: Sink>(&self, mut sink: S) -> Result<Vec<u8>> {
+        let path = PathBuf::from(self.clone());
+        let mut result = Vec::new();
+
+        std::fs::File::open(&path)
+            .map_err(reading(Step::Open, Some(&path)))
+            .and_then(|mut f| {
+                f.read_to_end(&mut result)
+                    .map_err(reading(Step::Read, Some(&path)))
+            })
+            .inspect_err(|e| sink.report(e))?;
+
+        sink.record(&Event::new(Operation::Read, Some(path), result.len()));
+        Ok(result)
+    }
+
     fn read_loudly(&self) -> Result<String> {
-        match std::fs::read_to_string(PathBuf::from(self.clone())) {
-            Ok(s) => Ok(s),
-            Err(e) => {
-                eprintln!("{e}");
-                Err(e.into())
-            }
-        }
+        self.read_with(StderrSink)
     }
 
     fn read_silently(&self) -> Result<String> {
-        match std::fs::read_to_string(PathBuf::from(self.clone())) {
-            Ok(s) => Ok(s),
-            Err(e) => Err(e.into()),
-        }
+        self.read_with(NullSink)
+    }
+
+    fn read_with<S: Sink>(&self, mut sink: S) -> Result<String> {
+        let path = PathBuf::from(self.clone());
+        let mut result = String::new();
+
+        std::fs::File::open(&path)
+            .map_err(reading(Step::Open, Some(&path)))
+            .and_then(|mut f| {
+                f.read_to_string(&mut result)
+                    .map_err(reading(Step::Read, Some(&path)))
+            })
+            .inspect_err(|e| sink.report(e))?;
+
+        sink.record(&Event::new(Operation::Read, Some(path), result.len()));
+        Ok(result)
     }
 }
 
@@ -197,6 +905,142 @@ pub trait VectorReader<B>
 where
     B: BufReadReader,
 {
+    /// Stream fixed-size chunks from a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// This method behaves just like
+    /// [`crate::VectorReader::chunks_silently`] despite also printing error
+    /// messages to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_loudly<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a;
+
+    /// Stream fixed-size chunks from a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// If the instance this method is called on is a non-empty collection, each
+    /// of its elements will be read.  Therefore, the elements need to implement
+    /// [`crate::PathBufLikeReader`].  In case the instance this method is
+    /// called on is an empty collection, the alternative will be read.
+    /// Therefore, it needs to implement [`crate::BufReadReader`].  The files
+    /// will be opened one after another and read lazily in chunks of the given
+    /// size.
+    ///
+    /// The return value is an iterator whose items are either the next chunk,
+    /// in case of success, or a [`crate::Error`] to describe the error
+    /// cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_silently<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a;
+
+    /// Stream fixed-size chunks from a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// This method behaves just like [`crate::VectorReader::chunks_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Panics
+    ///
+    /// The given size needs to be greater than zero.
+    fn chunks_with<'a, S: Sink + 'a>(
+        &self,
+        alternative: B,
+        size: usize,
+        sink: S,
+    ) -> Chunks<'a>
+    where
+        B: 'a;
+
+    /// Stream the lines of a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// This method behaves just like [`crate::VectorReader::lines_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    fn lines_loudly<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a;
+
+    /// Stream the lines of a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// If the instance this method is called on is a non-empty collection, each
+    /// of its elements will be read.  Therefore, the elements need to implement
+    /// [`crate::PathBufLikeReader`].  In case the instance this method is
+    /// called on is an empty collection, the alternative will be read.
+    /// Therefore, it needs to implement [`crate::BufReadReader`].  The files
+    /// will be opened one after another and read lazily line by line.
+    ///
+    /// The return value is an iterator whose items are either the next line
+    /// without its line ending, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    fn lines_silently<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a;
+
+    /// Stream the lines of a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// This method behaves just like [`crate::VectorReader::lines_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    fn lines_with<'a, S: Sink + 'a>(
+        &self,
+        alternative: B,
+        sink: S,
+    ) -> Lines<'a>
+    where
+        B: 'a;
+
+    /// Read bytes from a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// This method behaves just like
+    /// [`crate::VectorReader::read_bytes_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_loudly(&self, alternative: B) -> Result<Vec<u8>>;
+
+    /// Read bytes from a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// If the instance this method is called on is a non-empty collection, each
+    /// of its elements will be read.  Therefore, the elements need to implement
+    /// [`crate::PathBufLikeReader`].  In case the instance this method is
+    /// called on is an empty collection, the alternative will be read.
+    /// Therefore, it needs to implement [`crate::BufReadReader`].
+    ///
+    /// The return value is either the concatenated content as a [`Vec<u8>`],
+    /// in case of success, or a [`crate::Error`] to describe the error
+    /// cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_silently(&self, alternative: B) -> Result<Vec<u8>>;
+
+    /// Read bytes from a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// This method behaves just like
+    /// [`crate::VectorReader::read_bytes_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_bytes_with<S: Sink>(
+        &self,
+        alternative: B,
+        sink: S,
+    ) -> Result<Vec<u8>>;
+
     /// Read from a list of [`crate::PathBufLikeReader`]s.
     ///
     /// This method behaves just like [`crate::VectorReader::read_silently`]
@@ -204,7 +1048,7 @@ where
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_loudly(&self, alternative: B) -> Result<String>;
 
     /// Read from a list of [`crate::PathBufLikeReader`]s.
@@ -216,40 +1060,141 @@ where
     /// Therefore, it needs to implement [`crate::BufReadReader`].
     ///
     /// The return value is either the read content as a [`String`], in case of
-    /// success, or a [`sysexits::ExitCode`] to describe the error cause,
+    /// success, or a [`crate::Error`] to describe the error cause,
     /// otherwise.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn read_silently(&self, alternative: B) -> Result<String>;
+
+    /// Read from a list of [`crate::PathBufLikeReader`]s.
+    ///
+    /// This method behaves just like [`crate::VectorReader::read_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn read_with<S: Sink>(&self, alternative: B, sink: S) -> Result<String>;
 }
 
 impl<B: BufReadReader, P: PathBufLikeReader> VectorReader<B> for Vec<P> {
-    fn read_loudly(&self, alternative: B) -> Result<String> {
+    fn chunks_loudly<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a,
+    {
+        self.chunks_with(alternative, size, StderrSink)
+    }
+
+    fn chunks_silently<'a>(&self, alternative: B, size: usize) -> Chunks<'a>
+    where
+        B: 'a,
+    {
+        self.chunks_with(alternative, size, NullSink)
+    }
+
+    fn chunks_with<'a, S: Sink + 'a>(
+        &self,
+        alternative: B,
+        size: usize,
+        sink: S,
+    ) -> Chunks<'a>
+    where
+        B: 'a,
+    {
         if self.is_empty() {
-            alternative.read_loudly()
+            alternative.chunks_with(size, sink)
         } else {
-            let mut result = String::new();
+            let mut result = Chunks::new(Vec::new().into(), size, sink);
 
             for element in self {
-                result.push_str(element.read_loudly()?.as_str());
+                result.append(element.chunks_silently(size));
+            }
+
+            result
+        }
+    }
+
+    fn lines_loudly<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a,
+    {
+        self.lines_with(alternative, StderrSink)
+    }
+
+    fn lines_silently<'a>(&self, alternative: B) -> Lines<'a>
+    where
+        B: 'a,
+    {
+        self.lines_with(alternative, NullSink)
+    }
+
+    fn lines_with<'a, S: Sink + 'a>(&self, alternative: B, sink: S) -> Lines<'a>
+    where
+        B: 'a,
+    {
+        if self.is_empty() {
+            alternative.lines_with(sink)
+        } else {
+            let mut result = Lines::new(Vec::new().into(), sink);
+
+            for element in self {
+                result.append(element.lines_silently());
+            }
+
+            result
+        }
+    }
+
+    fn read_bytes_loudly(&self, alternative: B) -> Result<Vec<u8>> {
+        self.read_bytes_with(alternative, StderrSink)
+    }
+
+    fn read_bytes_silently(&self, alternative: B) -> Result<Vec<u8>> {
+        self.read_bytes_with(alternative, NullSink)
+    }
+
+    fn read_bytes_with<S: Sink>(
+        &self,
+        alternative: B,
+        mut sink: S,
+    ) -> Result<Vec<u8>> {
+        if self.is_empty() {
+            alternative.read_bytes_with(sink)
+        } else {
+            let mut result = Vec::new();
+
+            for element in self {
+                result.append(&mut element.read_bytes_with(&mut sink)?);
             }
 
             Ok(result)
         }
     }
 
+    fn read_loudly(&self, alternative: B) -> Result<String> {
+        self.read_with(alternative, StderrSink)
+    }
+
     fn read_silently(&self, alternative: B) -> Result<String> {
+        self.read_with(alternative, NullSink)
+    }
+
+    fn read_with<S: Sink>(
+        &self,
+        alternative: B,
+        mut sink: S,
+    ) -> Result<String> {
         if self.is_empty() {
-            alternative.read_silently()
+            alternative.read_with(sink)
         } else {
             let mut result = String::new();
 
             for element in self {
-                result.push_str(element.read_silently()?.as_str());
+                result.push_str(element.read_with(&mut sink)?.as_str());
             }
 
             Ok(result)
@@ -257,4 +1202,18 @@ impl<B: BufReadReader, P: PathBufLikeReader> VectorReader<B> for Vec<P> {
     }
 }
 
+fn reading(
+    step: Step,
+    path: Option<&Path>,
+) -> impl FnOnce(std::io::Error) -> Error + '_ {
+    move |e| {
+        Error::new(
+            Operation::Read,
+            step,
+            path.map(Path::to_path_buf),
+            Cause::Io(e),
+        )
+    }
+}
+
 /******************************************************************************/
@@ -0,0 +1,506 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    writing::text, Cause, Error, NullSink, Operation, PathBufLikeReader,
+    PathBufLikeTruncation, Payload, Result, Sink, StderrSink, Step,
+};
+use std::path::{Path, PathBuf};
+
+/// The syntax of the markers which delimit regions.
+///
+/// Both markers are given as templates in which `{}` stands for the name of
+/// the region.  By default, the markers of the Range of Mercy are used:
+/// `#[aeruginous::mercy::{}::start]` and `#[aeruginous::mercy::{}::end]`.
+/// Names must neither be empty nor contain whitespace.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Markers {
+    end: String,
+    start: String,
+}
+
+impl Markers {
+    /// Set the template of the end marker.
+    ///
+    /// # Panics
+    ///
+    /// The template needs to contain `{}`.
+    #[must_use]
+    pub fn end(mut self, template: &str) -> Self {
+        assert!(template.contains("{}"), "The template lacks `{{}}`.");
+        self.end = template.to_string();
+        self
+    }
+
+    /// Create a new instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            end: "#[aeruginous::mercy::{}::end]".to_string(),
+            start: "#[aeruginous::mercy::{}::start]".to_string(),
+        }
+    }
+
+    /// Set the template of the start marker.
+    ///
+    /// # Panics
+    ///
+    /// The template needs to contain `{}`.
+    #[must_use]
+    pub fn start(mut self, template: &str) -> Self {
+        assert!(template.contains("{}"), "The template lacks `{{}}`.");
+        self.start = template.to_string();
+        self
+    }
+
+    /// Find the bounds of the first region with the given name.
+    pub(crate) fn bounds(
+        &self,
+        content: &str,
+        name: &str,
+        operation: Operation,
+        path: &Path,
+    ) -> Result<(usize, usize)> {
+        self.named(content, name)
+            .map(|spans| (spans[0].begin, spans[0].end))
+            .map_err(|e| e.into_error(operation, path))
+    }
+
+    fn named(
+        &self,
+        content: &str,
+        name: &str,
+    ) -> std::result::Result<Vec<Span>, Syntax> {
+        let spans: Vec<_> = self
+            .scan(content)?
+            .into_iter()
+            .filter(|s| s.name == name)
+            .collect();
+
+        if spans.is_empty() {
+            Err(Syntax(
+                content.lines().count() + 1,
+                1,
+                format!("there is no region named '{name}'"),
+            ))
+        } else {
+            Ok(spans)
+        }
+    }
+
+    fn scan(&self, content: &str) -> std::result::Result<Vec<Span>, Syntax> {
+        let mut spans = Vec::new();
+        let mut open: Option<Span> = None;
+        let mut offset = 0;
+
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            if let Some((name, column, prefix)) = find(&self.start, line) {
+                if let Some(span) = &open {
+                    return Err(Syntax(
+                        index + 1,
+                        column,
+                        format!(
+                            "start marker of region '{name}' nested in region \
+                             '{}'",
+                            span.name
+                        ),
+                    ));
+                }
+
+                open = Some(Span {
+                    begin: offset + line.len(),
+                    end: 0,
+                    line: index + 1,
+                    name,
+                    prefix,
+                });
+            } else if let Some((name, column, _)) = find(&self.end, line) {
+                match open.take() {
+                    Some(mut span) if span.name == name => {
+                        span.end = offset;
+                        spans.push(span);
+                    }
+                    Some(span) => {
+                        return Err(Syntax(
+                            index + 1,
+                            column,
+                            format!(
+                                "end marker of region '{name}' closes region \
+                                 '{}'",
+                                span.name
+                            ),
+                        ));
+                    }
+                    None => {
+                        return Err(Syntax(
+                            index + 1,
+                            column,
+                            format!(
+                                "end marker of region '{name}' without start \
+                                 marker"
+                            ),
+                        ));
+                    }
+                }
+            }
+
+            offset += line.len();
+        }
+
+        open.map_or(Ok(spans), |span| {
+            Err(Syntax(
+                span.line,
+                1,
+                format!(
+                    "start marker of region '{}' without end marker",
+                    span.name
+                ),
+            ))
+        })
+    }
+}
+
+impl Default for Markers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A region delimited by a pair of markers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Region {
+    content: String,
+    line: usize,
+    name: String,
+    prefix: String,
+}
+
+impl Region {
+    /// The lines between the markers without the comment prefix.
+    #[must_use]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// The line of the start marker, starting at one.
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The name of this region.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The comment prefix of the start marker, such as `//! `.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+}
+
+/// Read the regions of files given as instances convertible to a
+/// [`std::path::PathBuf`].
+pub trait RegionReader {
+    /// List the regions of the file this method is called on.
+    ///
+    /// This method behaves just like [`crate::RegionReader::regions_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn regions_loudly(&self, markers: &Markers) -> Result<Vec<Region>>;
+
+    /// List the regions of the file this method is called on.
+    ///
+    /// The instance this method is called on needs to be convertible to a
+    /// [`std::path::PathBuf`].  The referenced file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and searched for the given
+    /// [`crate::Markers`].  The comment prefix of each start marker, such as
+    /// `//! ` or `# `, will be removed from the lines of its region.
+    ///
+    /// The return value is either the list of [`crate::Region`]s, in the
+    /// order of their appearance, in case of success, or a [`crate::Error`]
+    /// to describe the error cause, otherwise.  Unbalanced and nested markers
+    /// are reported as [`crate::Cause::Parse`].
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn regions_silently(&self, markers: &Markers) -> Result<Vec<Region>>;
+
+    /// List the regions of the file this method is called on.
+    ///
+    /// This method behaves just like [`crate::RegionReader::regions_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn regions_with<S: Sink>(
+        &self,
+        markers: &Markers,
+        sink: S,
+    ) -> Result<Vec<Region>>;
+}
+
+impl<T> RegionReader for T
+where
+    PathBuf: From<T>,
+    T: Clone,
+{
+    fn regions_loudly(&self, markers: &Markers) -> Result<Vec<Region>> {
+        self.regions_with(markers, StderrSink)
+    }
+
+    fn regions_silently(&self, markers: &Markers) -> Result<Vec<Region>> {
+        self.regions_with(markers, NullSink)
+    }
+
+    fn regions_with<S: Sink>(
+        &self,
+        markers: &Markers,
+        mut sink: S,
+    ) -> Result<Vec<Region>> {
+        let path = PathBuf::from(self.clone());
+        let content = path.read_with(&mut sink)?;
+
+        Ok(markers
+            .scan(&content)
+            .map_err(|e| e.into_error(Operation::Read, &path))
+            .inspect_err(|e| sink.report(e))?
+            .into_iter()
+            .map(|span| Region {
+                content: strip(&content[span.begin..span.end], &span.prefix),
+                line: span.line,
+                name: span.name,
+                prefix: span.prefix,
+            })
+            .collect())
+    }
+}
+
+/// Replace the regions of files given as instances convertible to a
+/// [`std::path::PathBuf`].
+pub trait RegionTruncation<T>
+where
+    PathBuf: From<T>,
+{
+    /// Replace the content of the given region.
+    ///
+    /// This method behaves just like
+    /// [`crate::RegionTruncation::truncate_region_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_region_loudly(
+        self,
+        destination: T,
+        name: &str,
+        markers: &Markers,
+    ) -> Result<()>;
+
+    /// Replace the content of the given region.
+    ///
+    /// The data this method is called on will be interpreted as UTF-8 text and
+    /// inserted between all pairs of the given [`crate::Markers`] with the
+    /// given name in the given file.  The comment prefix of the respective
+    /// start marker will be prepended to each line, whereby blank lines only
+    /// receive the prefix without trailing whitespace.  A missing final
+    /// newline will be added.  The file will be read by
+    /// [`crate::PathBufLikeReader::read_silently`] and truncated by
+    /// [`crate::PathBufLikeTruncation::truncate_silently`].
+    ///
+    /// The return value is either the unit type, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.  Unbalanced
+    /// and nested markers are reported as [`crate::Cause::Parse`], just like
+    /// missing regions.  Data which is no valid UTF-8 is reported as
+    /// [`std::io::ErrorKind::InvalidData`].
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_region_silently(
+        self,
+        destination: T,
+        name: &str,
+        markers: &Markers,
+    ) -> Result<()>;
+
+    /// Replace the content of the given region.
+    ///
+    /// This method behaves just like
+    /// [`crate::RegionTruncation::truncate_region_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_region_with<S: Sink>(
+        self,
+        destination: T,
+        name: &str,
+        markers: &Markers,
+        sink: S,
+    ) -> Result<()>;
+}
+
+impl<P, T: Payload> RegionTruncation<P> for T
+where
+    PathBuf: From<P>,
+{
+    fn truncate_region_loudly(
+        self,
+        destination: P,
+        name: &str,
+        markers: &Markers,
+    ) -> Result<()> {
+        self.truncate_region_with(destination, name, markers, StderrSink)
+    }
+
+    fn truncate_region_silently(
+        self,
+        destination: P,
+        name: &str,
+        markers: &Markers,
+    ) -> Result<()> {
+        self.truncate_region_with(destination, name, markers, NullSink)
+    }
+
+    fn truncate_region_with<S: Sink>(
+        self,
+        destination: P,
+        name: &str,
+        markers: &Markers,
+        mut sink: S,
+    ) -> Result<()> {
+        let path = PathBuf::from(destination);
+        let content = path.read_with(&mut sink)?;
+        let spans = markers
+            .named(&content, name)
+            .map_err(|e| e.into_error(Operation::Truncate, &path))
+            .inspect_err(|e| sink.report(e))?;
+
+        let mut data =
+            text(&self, Operation::Truncate).inspect_err(|e| sink.report(e))?;
+
+        if !data.is_empty() && !data.ends_with('\n') {
+            data.push('\n');
+        }
+
+        let mut result = String::with_capacity(content.len() + data.len());
+        let mut offset = 0;
+
+        for span in spans {
+            result.push_str(&content[offset..span.begin]);
+            result.push_str(&prefix(&data, &span.prefix));
+            offset = span.end;
+        }
+
+        result.push_str(&content[offset..]);
+        PathBufLikeTruncation::<PathBuf>::truncate_with(result, path, sink)
+    }
+}
+
+struct Span {
+    begin: usize,
+    end: usize,
+    line: usize,
+    name: String,
+    prefix: String,
+}
+
+struct Syntax(usize, usize, String);
+
+impl Syntax {
+    fn into_error(self, operation: Operation, path: &Path) -> Error {
+        Error::new(
+            operation,
+            Step::Parse,
+            Some(path.to_path_buf()),
+            Cause::Parse {
+                line: self.0,
+                column: self.1,
+                reason: self.2,
+            },
+        )
+    }
+}
+
+fn find(template: &str, line: &str) -> Option<(String, usize, String)> {
+    let (before, after) = template.split_once("{}")?;
+    let position = line.find(before)?;
+    let rest = &line[position + before.len()..];
+    let name = if after.is_empty() {
+        rest.split(char::is_whitespace).next().unwrap_or_default()
+    } else {
+        &rest[..rest.find(after)?]
+    };
+
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let prefix = line[..position]
+        .char_indices()
+        .find(|(_, c)| !c.is_whitespace() && !"!#%*-/;".contains(*c))
+        .map_or(&line[..position], |(i, _)| &line[..i]);
+
+    Some((
+        name.to_string(),
+        line[..position].chars().count() + 1,
+        prefix.to_string(),
+    ))
+}
+
+fn prefix(content: &str, prefix: &str) -> String {
+    content
+        .split_inclusive('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                let ending = line.trim_end_matches(['\n', '\r']).len();
+                format!("{}{}", prefix.trim_end(), &line[ending..])
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect()
+}
+
+fn strip(content: &str, prefix: &str) -> String {
+    content
+        .split_inclusive('\n')
+        .map(|line| {
+            line.strip_prefix(prefix)
+                .or_else(|| line.strip_prefix(prefix.trim_end()))
+                .unwrap_or(line)
+        })
+        .collect()
+}
+
+/******************************************************************************/
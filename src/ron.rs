@@ -0,0 +1,467 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{Cause, Error, Operation, Step};
+use std::{iter::Peekable, path::Path, str::Chars};
+
+/// The maximum nesting of values, such that malicious input cannot overflow
+/// the stack.
+const DEPTH: usize = 128;
+
+pub struct Node {
+    pub column: usize,
+    pub line: usize,
+    pub value: Value,
+}
+
+impl Node {
+    pub fn fields<const N: usize>(
+        &self,
+        names: [&str; N],
+    ) -> Result<[&Self; N], Syntax> {
+        let Value::Struct(fields) = &self.value else {
+            return Err(self.error("expected a structure".to_string()));
+        };
+        let mut result = [None; N];
+
+        for (name, node) in fields {
+            let index = names
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| node.error(format!("unknown field '{name}'")))?;
+
+            if result[index].replace(node).is_some() {
+                return Err(node.error(format!("duplicate field '{name}'")));
+            }
+        }
+
+        let mut missing =
+            names.iter().zip(&result).filter(|(_, n)| n.is_none());
+
+        if let Some((name, _)) = missing.next() {
+            return Err(self.error(format!("missing field '{name}'")));
+        }
+
+        Ok(result.map(|node| node.unwrap_or(self)))
+    }
+
+    pub fn integer(&self) -> Result<u64, Syntax> {
+        match self.value {
+            Value::Integer(integer) => Ok(integer),
+            _ => Err(self.error("expected an integer".to_string())),
+        }
+    }
+
+    pub fn list(&self) -> Result<&[Self], Syntax> {
+        match &self.value {
+            Value::List(list) => Ok(list),
+            _ => Err(self.error("expected a list".to_string())),
+        }
+    }
+
+    pub fn map(&self) -> Result<&[(Self, Self)], Syntax> {
+        match &self.value {
+            Value::Map(map) => Ok(map),
+            _ => Err(self.error("expected a map".to_string())),
+        }
+    }
+
+    pub const fn new(value: Value) -> Self {
+        Self {
+            column: 0,
+            line: 0,
+            value,
+        }
+    }
+
+    pub fn option(&self) -> Result<Option<&Self>, Syntax> {
+        match &self.value {
+            Value::Option(option) => Ok(option.as_deref()),
+            _ => Err(self.error("expected 'Some' or 'None'".to_string())),
+        }
+    }
+
+    pub fn render(&self, depth: usize) -> String {
+        let indentation = "  ".repeat(depth + 1);
+        let block = |open: &str, close: &str, items: Vec<String>| {
+            if items.is_empty() {
+                format!("{open}{close}")
+            } else {
+                format!(
+                    "{open}\n{}{}{close}",
+                    items
+                        .iter()
+                        .flat_map(|item| [&indentation, item, ",\n"])
+                        .collect::<String>(),
+                    "  ".repeat(depth),
+                )
+            }
+        };
+
+        match &self.value {
+            Value::Integer(integer) => integer.to_string(),
+            Value::List(list) => block(
+                "[",
+                "]",
+                list.iter().map(|node| node.render(depth + 1)).collect(),
+            ),
+            Value::Map(map) => block(
+                "{",
+                "}",
+                map.iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{}: {}",
+                            key.render(depth + 1),
+                            value.render(depth + 1)
+                        )
+                    })
+                    .collect(),
+            ),
+            Value::Option(None) => "None".to_string(),
+            Value::Option(Some(node)) => {
+                format!("Some({})", node.render(depth))
+            }
+            Value::String(string) => quote(string),
+            Value::Struct(fields) => block(
+                "(",
+                ")",
+                fields
+                    .iter()
+                    .map(|(name, node)| {
+                        format!("{name}: {}", node.render(depth + 1))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn string(&self) -> Result<&str, Syntax> {
+        match &self.value {
+            Value::String(string) => Ok(string),
+            _ => Err(self.error("expected a string".to_string())),
+        }
+    }
+
+    const fn error(&self, reason: String) -> Syntax {
+        Syntax(self.line, self.column, reason)
+    }
+}
+
+pub enum Value {
+    Integer(u64),
+    List(Vec<Node>),
+    Map(Vec<(Node, Node)>),
+    Option(Option<Box<Node>>),
+    String(String),
+    Struct(Vec<(String, Node)>),
+}
+
+pub struct Syntax(pub usize, pub usize, pub String);
+
+impl Syntax {
+    pub fn into_error(self, path: Option<&Path>) -> Error {
+        Error::new(
+            Operation::Read,
+            Step::Parse,
+            path.map(Path::to_path_buf),
+            Cause::Parse {
+                line: self.0,
+                column: self.1,
+                reason: self.2,
+            },
+        )
+    }
+}
+
+pub fn parse(input: &str) -> Result<Node, Syntax> {
+    let mut parser = Parser {
+        characters: input.chars().peekable(),
+        column: 1,
+        depth: 0,
+        line: 1,
+    };
+    let node = parser.value()?;
+
+    parser.skip();
+
+    if let Some(c) = parser.characters.peek().copied() {
+        return Err(parser.error(format!("unexpected '{c}'")));
+    }
+
+    Ok(node)
+}
+
+struct Parser<'a> {
+    characters: Peekable<Chars<'a>>,
+    column: usize,
+    depth: usize,
+    line: usize,
+}
+
+impl Parser<'_> {
+    const fn error(&self, reason: String) -> Syntax {
+        Syntax(self.line, self.column, reason)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Syntax> {
+        self.skip();
+
+        if self.characters.peek() == Some(&expected) {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("'{expected}'")))
+        }
+    }
+
+    fn identifier(&mut self) -> String {
+        let mut identifier = String::new();
+
+        while let Some(&c) = self.characters.peek() {
+            if c.is_alphanumeric() || c == '_' {
+                identifier.push(c);
+                self.next();
+            } else {
+                break;
+            }
+        }
+
+        identifier
+    }
+
+    fn items<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, Syntax>,
+    ) -> Result<Vec<T>, Syntax> {
+        let mut items = Vec::new();
+
+        loop {
+            self.skip();
+
+            if self.characters.peek() == Some(&close) {
+                self.next();
+                return Ok(items);
+            }
+
+            items.push(item(self)?);
+            self.skip();
+
+            match self.characters.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                Some(&c) if c == close => {}
+                _ => return Err(self.unexpected(&format!("',' or '{close}'"))),
+            }
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.characters.next()?;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn skip(&mut self) {
+        loop {
+            match self.characters.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.next();
+                }
+                Some('/') => {
+                    if self.characters.clone().nth(1) != Some('/') {
+                        return;
+                    }
+
+                    while !matches!(self.next(), None | Some('\n')) {}
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Syntax> {
+        let (line, column) = (self.line, self.column);
+        let mut string = String::new();
+
+        self.next();
+
+        loop {
+            match self.next() {
+                None => {
+                    return Err(Syntax(
+                        line,
+                        column,
+                        "unterminated string".to_string(),
+                    ))
+                }
+                Some('"') => return Ok(string),
+                Some('\\') => string.push(match self.next() {
+                    Some('0') => '\0',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some(c @ ('"' | '\'' | '\\')) => c,
+                    Some('u') => self.unicode()?,
+                    _ => return Err(self.error("invalid escape".to_string())),
+                }),
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn unexpected(&mut self, expected: &str) -> Syntax {
+        let found = self
+            .characters
+            .peek()
+            .map_or_else(|| "end of input".to_string(), |c| format!("'{c}'"));
+
+        self.error(format!("expected {expected}, found {found}"))
+    }
+
+    fn unicode(&mut self) -> Result<char, Syntax> {
+        self.expect('{')?;
+
+        let mut digits = String::new();
+
+        while let Some(c) = self.next() {
+            if c == '}' {
+                return u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error("invalid escape".to_string()));
+            }
+
+            digits.push(c);
+        }
+
+        Err(self.error("unterminated string".to_string()))
+    }
+
+    fn value(&mut self) -> Result<Node, Syntax> {
+        self.skip();
+
+        if self.depth == DEPTH {
+            return Err(
+                self.error(format!("values are nested deeper than {DEPTH}"))
+            );
+        }
+
+        self.depth += 1;
+        let node = self.node();
+        self.depth -= 1;
+        node
+    }
+
+    fn node(&mut self) -> Result<Node, Syntax> {
+        let (line, column) = (self.line, self.column);
+        let value = match self.characters.peek() {
+            Some('"') => Value::String(self.string()?),
+            Some('[') => {
+                self.next();
+                Value::List(self.items(']', Self::value)?)
+            }
+            Some('{') => {
+                self.next();
+                Value::Map(self.items('}', |parser| {
+                    let key = parser.value()?;
+                    parser.expect(':')?;
+                    Ok((key, parser.value()?))
+                })?)
+            }
+            Some('(') => self.structure()?,
+            Some(c) if c.is_ascii_digit() => {
+                let digits = self.identifier();
+
+                Value::Integer(digits.parse().map_err(|_| {
+                    Syntax(line, column, format!("invalid integer '{digits}'"))
+                })?)
+            }
+            Some(c) if c.is_alphabetic() || *c == '_' => {
+                match self.identifier().as_str() {
+                    "None" => Value::Option(None),
+                    "Some" => {
+                        self.expect('(')?;
+                        let node = self.value()?;
+                        self.expect(')')?;
+                        Value::Option(Some(Box::new(node)))
+                    }
+                    _ => {
+                        self.skip();
+                        self.structure()?
+                    }
+                }
+            }
+            _ => return Err(self.unexpected("a value")),
+        };
+
+        Ok(Node {
+            column,
+            line,
+            value,
+        })
+    }
+
+    fn structure(&mut self) -> Result<Value, Syntax> {
+        self.expect('(')?;
+
+        Ok(Value::Struct(self.items(')', |parser| {
+            parser.skip();
+
+            let name = parser.identifier();
+
+            if name.is_empty() {
+                return Err(parser.unexpected("a field name"));
+            }
+
+            parser.expect(':')?;
+            Ok((name, parser.value()?))
+        })?))
+    }
+}
+
+fn quote(string: &str) -> String {
+    let mut result = String::from('"');
+
+    for c in string.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\0' => result.push_str("\\0"),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+/******************************************************************************/
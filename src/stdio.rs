@@ -0,0 +1,336 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    BufReadReader, Check, Chunks, Diff, Lines, NullSink, PathBufLikeAppendix,
+    PathBufLikeReader, PathBufLikeTruncation, Payload, Result, Sink,
+    StderrSink, Writer,
+};
+use std::path::{Path, PathBuf};
+
+/// A path which denotes the standard streams if it is `-`.
+///
+/// Following the convention of Unix command line tools, this path is read from
+/// [`std::io::Stdin`] and written to [`std::io::Stdout`] if it is `-`.  Any
+/// other path refers to a file, just like a [`std::path::PathBuf`] does.  As
+/// this convention is opt-in, plain paths named `-` still refer to files.
+///
+/// The reading and writing traits are implemented for this type as well as
+/// for lists and options of it.  Hence, concatenating files and the standard
+/// input works just like `cat a - b`.  As the standard input is locked while
+/// it is read, alternatives should not hold a [`std::io::StdinLock`] when the
+/// list might contain `-`.
+///
+/// As the standard output cannot be read back, it is treated like a file
+/// which always differs from the data to write.  Hence,
+/// [`crate::PathBufLikeTruncation::check_silently`] always reports
+/// [`crate::Check::WouldModify`] for `-`,
+/// [`crate::PathBufLikeTruncation::truncate_if_changed_silently`] always
+/// writes the data and returns `true`, and dry runs show the data as added to
+/// an empty file.
+///
+/// ```rust
+/// use aeruginous_io::{StdioPath, VectorReader};
+///
+/// let inputs = ["tests/assets/GPL-3.0.rs", "tests/assets/line-endings.txt"]
+///     .map(StdioPath::from)
+///     .to_vec();
+///
+/// assert!(!inputs[0].is_stdio());
+/// assert!(StdioPath::from("-").is_stdio());
+/// assert!(inputs
+///     .read_silently(std::io::BufReader::new(std::io::stdin()))
+///     .is_ok());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StdioPath(PathBuf);
+
+impl StdioPath {
+    /// Whether this path denotes the standard streams.
+    #[must_use]
+    pub fn is_stdio(&self) -> bool {
+        self.0.as_os_str() == "-"
+    }
+
+    /// The path this instance wraps.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl<T> From<T> for StdioPath
+where
+    PathBuf: From<T>,
+{
+    fn from(path: T) -> Self {
+        Self(PathBuf::from(path))
+    }
+}
+
+impl<T: Payload> PathBufLikeAppendix<StdioPath> for T {
+    fn append_dry_run_loudly(
+        self,
+        destination: StdioPath,
+        diff: Diff,
+    ) -> Result<String> {
+        self.append_dry_run_with(destination, diff, StderrSink)
+    }
+
+    fn append_dry_run_silently(
+        self,
+        destination: StdioPath,
+        diff: Diff,
+    ) -> Result<String> {
+        self.append_dry_run_with(destination, diff, NullSink)
+    }
+
+    fn append_dry_run_with<S: Sink>(
+        self,
+        destination: StdioPath,
+        diff: Diff,
+        sink: S,
+    ) -> Result<String> {
+        if destination.is_stdio() {
+            Ok(unified(&self, diff))
+        } else {
+            self.append_dry_run_with(destination.0, diff, sink)
+        }
+    }
+
+    fn append_loudly(self, destination: StdioPath) -> Result<()> {
+        PathBufLikeAppendix::append_with(self, destination, StderrSink)
+    }
+
+    fn append_silently(self, destination: StdioPath) -> Result<()> {
+        PathBufLikeAppendix::append_with(self, destination, NullSink)
+    }
+
+    fn append_with<S: Sink>(
+        self,
+        destination: StdioPath,
+        sink: S,
+    ) -> Result<()> {
+        if destination.is_stdio() {
+            self.write_with(std::io::stdout(), sink)
+        } else {
+            PathBufLikeAppendix::append_with(self, destination.0, sink)
+        }
+    }
+}
+
+impl PathBufLikeReader for StdioPath {
+    fn chunks_loudly(&self, size: usize) -> Chunks<'static> {
+        self.chunks_with(size, StderrSink)
+    }
+
+    fn chunks_silently(&self, size: usize) -> Chunks<'static> {
+        self.chunks_with(size, NullSink)
+    }
+
+    fn chunks_with<'a, S: Sink + 'a>(
+        &self,
+        size: usize,
+        sink: S,
+    ) -> Chunks<'a> {
+        if self.is_stdio() {
+            std::io::stdin().lock().chunks_with(size, sink)
+        } else {
+            self.0.chunks_with(size, sink)
+        }
+    }
+
+    fn lines_loudly(&self) -> Lines<'static> {
+        self.lines_with(StderrSink)
+    }
+
+    fn lines_silently(&self) -> Lines<'static> {
+        self.lines_with(NullSink)
+    }
+
+    fn lines_with<'a, S: Sink + 'a>(&self, sink: S) -> Lines<'a> {
+        if self.is_stdio() {
+            std::io::stdin().lock().lines_with(sink)
+        } else {
+            self.0.lines_with(sink)
+        }
+    }
+
+    fn read_bytes_loudly(&self) -> Result<Vec<u8>> {
+        self.read_bytes_with(StderrSink)
+    }
+
+    fn read_bytes_silently(&self) -> Result<Vec<u8>> {
+        self.read_bytes_with(NullSink)
+    }
+
+    fn read_bytes_with<S: Sink>(&self, sink: S) -> Result<Vec<u8>> {
+        if self.is_stdio() {
+            std::io::stdin().lock().read_bytes_with(sink)
+        } else {
+            self.0.read_bytes_with(sink)
+        }
+    }
+
+    fn read_loudly(&self) -> Result<String> {
+        self.read_with(StderrSink)
+    }
+
+    fn read_silently(&self) -> Result<String> {
+        self.read_with(NullSink)
+    }
+
+    fn read_with<S: Sink>(&self, sink: S) -> Result<String> {
+        if self.is_stdio() {
+            std::io::stdin().lock().read_with(sink)
+        } else {
+            self.0.read_with(sink)
+        }
+    }
+}
+
+impl<T: Payload> PathBufLikeTruncation<StdioPath> for T {
+    fn check_loudly(self, destination: StdioPath) -> Result<Check> {
+        PathBufLikeTruncation::check_with(self, destination, StderrSink)
+    }
+
+    fn check_silently(self, destination: StdioPath) -> Result<Check> {
+        PathBufLikeTruncation::check_with(self, destination, NullSink)
+    }
+
+    fn check_with<S: Sink>(
+        self,
+        destination: StdioPath,
+        sink: S,
+    ) -> Result<Check> {
+        if destination.is_stdio() {
+            Ok(Check::WouldModify)
+        } else {
+            PathBufLikeTruncation::check_with(self, destination.0, sink)
+        }
+    }
+
+    fn truncate_atomically_loudly(self, destination: StdioPath) -> Result<()> {
+        self.truncate_atomically_with(destination, StderrSink)
+    }
+
+    fn truncate_atomically_silently(
+        self,
+        destination: StdioPath,
+    ) -> Result<()> {
+        self.truncate_atomically_with(destination, NullSink)
+    }
+
+    fn truncate_atomically_with<S: Sink>(
+        self,
+        destination: StdioPath,
+        sink: S,
+    ) -> Result<()> {
+        if destination.is_stdio() {
+            self.write_with(std::io::stdout(), sink)
+        } else {
+            self.truncate_atomically_with(destination.0, sink)
+        }
+    }
+
+    fn truncate_dry_run_loudly(
+        self,
+        destination: StdioPath,
+        diff: Diff,
+    ) -> Result<String> {
+        self.truncate_dry_run_with(destination, diff, StderrSink)
+    }
+
+    fn truncate_dry_run_silently(
+        self,
+        destination: StdioPath,
+        diff: Diff,
+    ) -> Result<String> {
+        self.truncate_dry_run_with(destination, diff, NullSink)
+    }
+
+    fn truncate_dry_run_with<S: Sink>(
+        self,
+        destination: StdioPath,
+        diff: Diff,
+        sink: S,
+    ) -> Result<String> {
+        if destination.is_stdio() {
+            Ok(unified(&self, diff))
+        } else {
+            self.truncate_dry_run_with(destination.0, diff, sink)
+        }
+    }
+
+    fn truncate_if_changed_loudly(
+        self,
+        destination: StdioPath,
+    ) -> Result<bool> {
+        self.truncate_if_changed_with(destination, StderrSink)
+    }
+
+    fn truncate_if_changed_silently(
+        self,
+        destination: StdioPath,
+    ) -> Result<bool> {
+        self.truncate_if_changed_with(destination, NullSink)
+    }
+
+    fn truncate_if_changed_with<S: Sink>(
+        self,
+        destination: StdioPath,
+        sink: S,
+    ) -> Result<bool> {
+        if destination.is_stdio() {
+            self.write_with(std::io::stdout(), sink).map(|()| true)
+        } else {
+            self.truncate_if_changed_with(destination.0, sink)
+        }
+    }
+
+    fn truncate_loudly(self, destination: StdioPath) -> Result<()> {
+        PathBufLikeTruncation::truncate_with(self, destination, StderrSink)
+    }
+
+    fn truncate_silently(self, destination: StdioPath) -> Result<()> {
+        PathBufLikeTruncation::truncate_with(self, destination, NullSink)
+    }
+
+    fn truncate_with<S: Sink>(
+        self,
+        destination: StdioPath,
+        sink: S,
+    ) -> Result<()> {
+        if destination.is_stdio() {
+            self.write_with(std::io::stdout(), sink)
+        } else {
+            PathBufLikeTruncation::truncate_with(self, destination.0, sink)
+        }
+    }
+}
+
+fn unified<T: Payload>(data: &T, diff: Diff) -> String {
+    diff.unified(
+        Path::new("-"),
+        None,
+        &String::from_utf8_lossy(&data.to_bytes()),
+    )
+}
+
+/******************************************************************************/
@@ -0,0 +1,254 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{Cause, Error, Operation, Result, Sink, Step};
+use std::{
+    collections::VecDeque,
+    io::{BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+/// A source to stream from.
+pub enum Source<'a> {
+    /// A file which is not opened before it is needed.
+    Path(PathBuf),
+
+    /// An arbitrary reader.
+    Reader(Box<dyn Read + 'a>),
+}
+
+impl<'a> Source<'a> {
+    fn open(self) -> std::io::Result<Box<dyn Read + 'a>> {
+        match self {
+            Self::Path(p) => Ok(Box::new(std::fs::File::open(p)?)),
+            Self::Reader(r) => Ok(r),
+        }
+    }
+
+    fn path(&self) -> Option<PathBuf> {
+        match self {
+            Self::Path(p) => Some(p.clone()),
+            Self::Reader(_) => None,
+        }
+    }
+}
+
+/// The concatenation of several sources.
+pub struct Chain<'a> {
+    current: Option<Box<dyn Read + 'a>>,
+    path: Option<PathBuf>,
+    pending: VecDeque<Source<'a>>,
+    step: Step,
+}
+
+impl Chain<'_> {
+    fn append(&mut self, mut other: Self) {
+        if let Some(r) = other.current.take() {
+            self.pending.push_back(Source::Reader(r));
+        }
+
+        self.pending.append(&mut other.pending);
+    }
+
+    fn error(&self, error: std::io::Error) -> Error {
+        Error::new(
+            Operation::Read,
+            self.step,
+            self.path.clone(),
+            Cause::Io(error),
+        )
+    }
+}
+
+impl<'a> From<Vec<Source<'a>>> for Chain<'a> {
+    fn from(sources: Vec<Source<'a>>) -> Self {
+        Self {
+            current: None,
+            path: None,
+            pending: sources.into(),
+            step: Step::Open,
+        }
+    }
+}
+
+impl Read for Chain<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if self.current.is_none() {
+                match self.pending.pop_front() {
+                    Some(s) => {
+                        self.path = s.path();
+                        self.step = Step::Open;
+                        self.current = Some(s.open()?);
+                        self.step = Step::Read;
+                    }
+                    None => return Ok(0),
+                }
+            }
+
+            if let Some(r) = &mut self.current {
+                match r.read(buf)? {
+                    0 if !buf.is_empty() => self.current = None,
+                    n => return Ok(n),
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over fixed-size chunks of bytes.
+///
+/// Instances are created by the `chunks_*` methods of this crate's reading
+/// traits.  Each item is either the next chunk, in case
+/// of success, or a [`crate::Error`] to describe the error cause,
+/// otherwise.  All chunks will have the requested size, except for the last
+/// one, which might be shorter.  Subsequent sources are treated as one
+/// continuous stream.  The iteration ends after the first error.
+pub struct Chunks<'a> {
+    done: bool,
+    reader: Chain<'a>,
+    sink: Box<dyn Sink + 'a>,
+    size: usize,
+}
+
+impl<'a> Chunks<'a> {
+    pub(crate) fn new<S: Sink + 'a>(
+        reader: Chain<'a>,
+        size: usize,
+        sink: S,
+    ) -> Self {
+        assert!(size > 0, "The chunk size needs to be greater than zero.");
+
+        Self {
+            done: false,
+            reader,
+            sink: Box::new(sink),
+            size,
+        }
+    }
+
+    pub(crate) fn append(&mut self, other: Self) {
+        self.reader.append(other.reader);
+    }
+}
+
+impl Iterator for Chunks<'_> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut chunk = vec![0; self.size];
+        let mut filled = 0;
+
+        while filled < self.size {
+            match self.reader.read(&mut chunk[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    let e = self.reader.error(e);
+                    self.done = true;
+                    self.sink.report(&e);
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        if filled == 0 {
+            self.done = true;
+            None
+        } else {
+            chunk.truncate(filled);
+            Some(Ok(chunk))
+        }
+    }
+}
+
+/// An iterator over lines.
+///
+/// Instances are created by the `lines_*` methods of this crate's reading
+/// traits.  Each item is either the next line without its
+/// line ending, in case of success, or a [`crate::Error`] to describe the
+/// error cause, otherwise.  Subsequent sources are treated as one continuous
+/// stream such that a source without a final newline continues with the first
+/// line of its successor.  The iteration ends after the first error.
+pub struct Lines<'a> {
+    done: bool,
+    reader: BufReader<Chain<'a>>,
+    sink: Box<dyn Sink + 'a>,
+}
+
+impl<'a> Lines<'a> {
+    pub(crate) fn new<S: Sink + 'a>(reader: Chain<'a>, sink: S) -> Self {
+        Self {
+            done: false,
+            reader: BufReader::new(reader),
+            sink: Box::new(sink),
+        }
+    }
+
+    pub(crate) fn append(&mut self, other: Self) {
+        self.reader.get_mut().append(other.reader.into_inner());
+    }
+}
+
+impl Iterator for Lines<'_> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut line = Vec::new();
+
+        let result = match self.reader.read_until(b'\n', &mut line) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => {
+                if line.ends_with(b"\n") {
+                    line.pop();
+
+                    if line.ends_with(b"\r") {
+                        line.pop();
+                    }
+                }
+
+                String::from_utf8(line).map_err(|e| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+                })
+            }
+            Err(e) => Err(e),
+        };
+
+        Some(result.map_err(|e| {
+            let e = self.reader.get_ref().error(e);
+            self.done = true;
+            self.sink.report(&e);
+            e
+        }))
+    }
+}
+
+/******************************************************************************/
@@ -0,0 +1,544 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+use crate::{
+    writing::Staged, Cause, Error, Event, NullSink, Operation,
+    PathBufLikeReader, Result, Sink, StderrSink, Step,
+};
+use std::path::{Path, PathBuf};
+
+/// The parts of a version to increment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Bump {
+    /// Increment the major version and reset the others.
+    Major,
+
+    /// Increment the minor version and reset the patch version.
+    Minor,
+
+    /// Increment the patch version.
+    Patch,
+}
+
+/// A semantic version without pre-release or build information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl Version {
+    /// Increment this version as requested.
+    #[must_use]
+    pub const fn bump(self, bump: Bump) -> Self {
+        match bump {
+            Bump::Major => Self::new(self.major + 1, 0, 0),
+            Bump::Minor => Self::new(self.major, self.minor + 1, 0),
+            Bump::Patch => Self::new(self.major, self.minor, self.patch + 1),
+        }
+    }
+
+    /// The major version.
+    #[must_use]
+    pub const fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// The minor version.
+    #[must_use]
+    pub const fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// Create a new instance.
+    #[must_use]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// The patch version.
+    #[must_use]
+    pub const fn patch(&self) -> u64 {
+        self.patch
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.split('.').map(|part| {
+            part.bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| part.parse().ok())
+                .flatten()
+        });
+        let version = Self::new(parts.next()??, parts.next()??, parts.next()??);
+
+        parts.next().is_none().then_some(version)
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Synchronise the version of a list of files.
+///
+/// The file format is determined by the file name:
+///
+/// | File name         | Location of the version                          |
+/// |:------------------|:-------------------------------------------------|
+/// | `.version`        | the whole, trimmed file                          |
+/// | `Cargo.toml`      | the `version` key of the `[package]` table       |
+/// | `CHANGELOG.ron`   | the `version` of the first, i.e. latest, section |
+/// | `CITATION.cff`    | the top-level `version` key                      |
+/// | `*.rs`            | the `VERSION` constant, with an optional `v`     |
+///
+/// `CHANGELOG.ron` is only read since its latest section describes a release
+/// which already happened.  New sections are added by
+/// [`crate::FragmentDirectory::release_fragments_silently`].
+pub trait VersionSynchroniser {
+    /// Increment the version of all files of this list.
+    ///
+    /// This method behaves just like
+    /// [`crate::VersionSynchroniser::bump_versions_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn bump_versions_loudly(&self, bump: Bump) -> Result<Version>;
+
+    /// Increment the version of all files of this list.
+    ///
+    /// The highest version found by
+    /// [`crate::VersionSynchroniser::versions_silently`] will be incremented
+    /// and written to all files by
+    /// [`crate::VersionSynchroniser::set_versions_silently`].
+    ///
+    /// The return value is either the new [`crate::Version`], in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn bump_versions_silently(&self, bump: Bump) -> Result<Version>;
+
+    /// Increment the version of all files of this list.
+    ///
+    /// This method behaves just like
+    /// [`crate::VersionSynchroniser::bump_versions_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn bump_versions_with<S: Sink>(
+        &self,
+        bump: Bump,
+        sink: S,
+    ) -> Result<Version>;
+
+    /// Set the version of all files of this list.
+    ///
+    /// This method behaves just like
+    /// [`crate::VersionSynchroniser::set_versions_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn set_versions_loudly(&self, version: Version) -> Result<()>;
+
+    /// Set the version of all files of this list.
+    ///
+    /// All files will be read and their versions located before any of them
+    /// is written.  Only the version itself will be replaced, the remainder
+    /// of each file is kept as is.  The changed files are staged as temporary
+    /// files next to their destinations and only renamed once all of them
+    /// could be staged such that a failure leaves all files untouched.
+    /// `CHANGELOG.ron` is never written but its latest version must not
+    /// exceed the given one.
+    ///
+    /// The return value is either the unit type, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn set_versions_silently(&self, version: Version) -> Result<()>;
+
+    /// Set the version of all files of this list.
+    ///
+    /// This method behaves just like
+    /// [`crate::VersionSynchroniser::set_versions_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn set_versions_with<S: Sink>(
+        &self,
+        version: Version,
+        sink: S,
+    ) -> Result<()>;
+
+    /// Read the version of all files of this list.
+    ///
+    /// This method behaves just like
+    /// [`crate::VersionSynchroniser::versions_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn versions_loudly(&self) -> Result<Vec<(PathBuf, Version)>>;
+
+    /// Read the version of all files of this list.
+    ///
+    /// Each element of the instance this method is called on needs to be
+    /// convertible to a [`std::path::PathBuf`].  The referenced files will be
+    /// read by [`crate::PathBufLikeReader::read_silently`] and searched for
+    /// their version.  Files without a version, with a malformed one or of an
+    /// unknown format are reported as [`crate::Cause::Parse`].
+    ///
+    /// The return value is either the [`crate::Version`] of each file, in the
+    /// order of the list, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  Inconsistencies can be detected
+    /// by comparing the versions.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn versions_silently(&self) -> Result<Vec<(PathBuf, Version)>>;
+
+    /// Read the version of all files of this list.
+    ///
+    /// This method behaves just like
+    /// [`crate::VersionSynchroniser::versions_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn versions_with<S: Sink>(
+        &self,
+        sink: S,
+    ) -> Result<Vec<(PathBuf, Version)>>;
+}
+
+impl<P> VersionSynchroniser for Vec<P>
+where
+    PathBuf: From<P>,
+    P: Clone,
+{
+    fn bump_versions_loudly(&self, bump: Bump) -> Result<Version> {
+        self.bump_versions_with(bump, StderrSink)
+    }
+
+    fn bump_versions_silently(&self, bump: Bump) -> Result<Version> {
+        self.bump_versions_with(bump, NullSink)
+    }
+
+    fn bump_versions_with<S: Sink>(
+        &self,
+        bump: Bump,
+        mut sink: S,
+    ) -> Result<Version> {
+        let version = self
+            .versions_with(&mut sink)?
+            .into_iter()
+            .map(|(_, version)| version)
+            .max()
+            .unwrap_or_default()
+            .bump(bump);
+
+        self.set_versions_with(version, sink)?;
+        Ok(version)
+    }
+
+    fn set_versions_loudly(&self, version: Version) -> Result<()> {
+        self.set_versions_with(version, StderrSink)
+    }
+
+    fn set_versions_silently(&self, version: Version) -> Result<()> {
+        self.set_versions_with(version, NullSink)
+    }
+
+    fn set_versions_with<S: Sink>(
+        &self,
+        version: Version,
+        mut sink: S,
+    ) -> Result<()> {
+        let mut staged = Vec::new();
+
+        for element in self {
+            let path = PathBuf::from(element.clone());
+            let content = path.read_with(&mut sink)?;
+            let (current, spans) =
+                locate(&path, &content).inspect_err(|e| sink.report(e))?;
+
+            if matches!(Manifest::from_path(&path), Some(Manifest::Changelog)) {
+                if current > version {
+                    let e = error(
+                        &path,
+                        &content,
+                        spans[0].0,
+                        format!("released version {current} exceeds {version}"),
+                    );
+
+                    sink.report(&e);
+                    return Err(e);
+                }
+            } else if current != version {
+                let mut result = content;
+
+                for ((begin, end), text) in
+                    spans.iter().zip(texts(version, spans.len())).rev()
+                {
+                    result.replace_range(begin..end, &text);
+                }
+
+                staged.push(
+                    Staged::new(&path, &result)
+                        .inspect_err(|e| sink.report(e))?,
+                );
+            }
+        }
+
+        for file in staged {
+            let path = file.path().to_path_buf();
+            let written = file.commit().inspect_err(|e| sink.report(e))?;
+
+            sink.record(&Event::new(Operation::Truncate, Some(path), written));
+        }
+
+        Ok(())
+    }
+
+    fn versions_loudly(&self) -> Result<Vec<(PathBuf, Version)>> {
+        self.versions_with(StderrSink)
+    }
+
+    fn versions_silently(&self) -> Result<Vec<(PathBuf, Version)>> {
+        self.versions_with(NullSink)
+    }
+
+    fn versions_with<S: Sink>(
+        &self,
+        mut sink: S,
+    ) -> Result<Vec<(PathBuf, Version)>> {
+        let mut result = Vec::new();
+
+        for element in self {
+            let path = PathBuf::from(element.clone());
+            let content = path.read_with(&mut sink)?;
+            let (version, _) =
+                locate(&path, &content).inspect_err(|e| sink.report(e))?;
+
+            result.push((path, version));
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Manifest {
+    Cargo,
+    Changelog,
+    Citation,
+    Plain,
+    Rust,
+}
+
+impl Manifest {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.file_name()?.to_str()? {
+            "Cargo.toml" => Some(Self::Cargo),
+            "CHANGELOG.ron" => Some(Self::Changelog),
+            "CITATION.cff" => Some(Self::Citation),
+            ".version" => Some(Self::Plain),
+            _ if path.extension().is_some_and(|e| e == "rs") => {
+                Some(Self::Rust)
+            }
+            _ => None,
+        }
+    }
+
+    fn spans(self, content: &str) -> Option<Vec<(usize, usize)>> {
+        if matches!(self, Self::Changelog) {
+            let mut position = content.find("version:")?;
+            let mut spans = Vec::new();
+
+            for key in ["major:", "minor:", "patch:"] {
+                let rest = &content[position..];
+                let value = &rest[rest.find(key)? + key.len()..];
+                let begin = content.len() - value.trim_start().len();
+                let end = begin
+                    + value
+                        .trim_start()
+                        .bytes()
+                        .take_while(u8::is_ascii_digit)
+                        .count();
+
+                spans.push((begin, end));
+                position = end;
+            }
+
+            return Some(spans);
+        }
+
+        if matches!(self, Self::Plain) {
+            let begin = content.len() - content.trim_start().len();
+            let end = begin + content.trim().len();
+
+            return (begin < end).then(|| vec![(begin, end)]);
+        }
+
+        let mut offset = 0;
+        let mut package = false;
+
+        for line in content.split_inclusive('\n') {
+            let trimmed = line.trim();
+            let span = match self {
+                Self::Cargo if trimmed.starts_with('[') => {
+                    package = trimmed == "[package]";
+                    None
+                }
+                Self::Cargo => trimmed
+                    .strip_prefix("version")
+                    .filter(|rest| {
+                        package && rest.trim_start().starts_with('=')
+                    })
+                    .and_then(|_| quoted(line)),
+                Self::Citation => line.strip_prefix("version:").map(|value| {
+                    let value = value.trim();
+                    let value = value
+                        .strip_prefix(['"', '\''])
+                        .and_then(|value| value.strip_suffix(['"', '\'']))
+                        .unwrap_or(value);
+                    let begin = line.find(value).unwrap_or_default();
+
+                    (begin, begin + value.len())
+                }),
+                Self::Rust if line.contains("const VERSION") => quoted(line)
+                    .map(|(begin, end)| {
+                        if line[begin..end].starts_with('v') {
+                            (begin + 1, end)
+                        } else {
+                            (begin, end)
+                        }
+                    }),
+                _ => None,
+            };
+
+            if let Some((begin, end)) = span {
+                return Some(vec![(offset + begin, offset + end)]);
+            }
+
+            offset += line.len();
+        }
+
+        None
+    }
+}
+
+fn error(file: &Path, content: &str, offset: usize, reason: String) -> Error {
+    let before = &content[..offset];
+
+    Error::new(
+        Operation::Read,
+        Step::Parse,
+        Some(file.to_path_buf()),
+        Cause::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before.len() - before.rfind('\n').map_or(0, |n| n + 1) + 1,
+            reason,
+        },
+    )
+}
+
+fn locate(
+    file: &Path,
+    content: &str,
+) -> Result<(Version, Vec<(usize, usize)>)> {
+    let error = |offset, reason| error(file, content, offset, reason);
+    let manifest = Manifest::from_path(file)
+        .ok_or_else(|| error(0, "unknown file format".to_string()))?;
+    let spans = manifest
+        .spans(content)
+        .ok_or_else(|| error(0, "there is no version".to_string()))?;
+    let text = |(begin, end): (usize, usize)| &content[begin..end];
+    let version = if spans.len() == 3 {
+        match spans
+            .iter()
+            .map(|&span| text(span).parse().ok())
+            .collect::<Option<Vec<u64>>>()
+            .as_deref()
+        {
+            Some(&[major, minor, patch]) => {
+                Some(Version::new(major, minor, patch))
+            }
+            _ => None,
+        }
+    } else {
+        Version::parse(text(spans[0]))
+    };
+
+    version
+        .map(|version| (version, spans.clone()))
+        .ok_or_else(|| {
+            error(
+                spans[0].0,
+                format!("malformed version '{}'", text(spans[0])),
+            )
+        })
+}
+
+fn quoted(line: &str) -> Option<(usize, usize)> {
+    let begin = line.find('"')? + 1;
+    let end = begin + line[begin..].find('"')?;
+
+    Some((begin, end))
+}
+
+fn texts(version: Version, count: usize) -> Vec<String> {
+    if count == 3 {
+        vec![
+            version.major.to_string(),
+            version.minor.to_string(),
+            version.patch.to_string(),
+        ]
+    } else {
+        vec![version.to_string()]
+    }
+}
+
+/******************************************************************************/
@@ -17,15 +17,218 @@
 |                                                                              |
 \******************************************************************************/
 
-use std::{io::Write, path::PathBuf};
-use sysexits::Result;
+use crate::{
+    Cause, Diff, Error, Event, NullSink, Operation, Result, Sink, StderrSink,
+    Step,
+};
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    io::{ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// The result of checking whether a destination is up to date.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Check {
+    /// The destination already holds the data.
+    Unchanged,
+
+    /// The destination does not exist, yet, and would be created.
+    WouldCreate,
+
+    /// The destination holds different data and would be modified.
+    WouldModify,
+}
+
+impl Check {
+    /// The [`sysexits::ExitCode`] which describes this result best.
+    ///
+    /// Up to date destinations result in `Ok` whereas outdated ones result in
+    /// `DataErr`.
+    #[must_use]
+    pub const fn exit_code(self) -> sysexits::ExitCode {
+        match self {
+            Self::Unchanged => sysexits::ExitCode::Ok,
+            Self::WouldCreate | Self::WouldModify => {
+                sysexits::ExitCode::DataErr
+            }
+        }
+    }
+}
+
+impl From<Check> for sysexits::ExitCode {
+    fn from(check: Check) -> Self {
+        check.exit_code()
+    }
+}
+
+/// Binary data to be written by the writing traits of this crate.
+///
+/// Types implementing [`std::fmt::Display`] can be written directly.  Raw
+/// bytes, which do not need to be valid UTF-8, can be written by wrapping them
+/// into this type, instead.  They will be passed to the destination as they
+/// are, without being copied.
+///
+/// ```rust
+/// use aeruginous_io::{Bytes, Writer};
+///
+/// let mut buffer = Vec::new();
+///
+/// Bytes(&[0xff, 0x00][..]).write_silently(&mut buffer).unwrap();
+/// assert_eq!(buffer, [0xff, 0x00]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bytes<B: AsRef<[u8]>>(pub B);
+
+/// Data which can be written by the writing traits of this crate.
+///
+/// This trait is implemented for all types implementing
+/// [`std::fmt::Display`] and for [`crate::Bytes`].  Formatted data is streamed
+/// through a buffer to the destination such that no intermediate [`String`]
+/// needs to be built.  Only checks and comparisons, which need the complete
+/// data at once, render it into memory.
+pub trait Payload {
+    /// Pass the bytes of this payload piece by piece to the given consumer.
+    ///
+    /// # Errors
+    ///
+    /// Errors of the consumer are passed through.  If formatting fails, an
+    /// error of the kind [`std::io::ErrorKind::Other`] will be returned.
+    fn stream(
+        &self,
+        consumer: &mut dyn FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> std::io::Result<()>;
+
+    /// The number of bytes of this payload, if known in advance.
+    ///
+    /// Formatted data is only measured while being streamed, hence the default
+    /// is [`None`].
+    fn size(&self) -> Option<usize> {
+        None
+    }
+
+    /// The complete bytes of this payload.
+    fn to_bytes(&self) -> Cow<'_, [u8]>;
+}
+
+impl<B: AsRef<[u8]>> Payload for Bytes<B> {
+    fn stream(
+        &self,
+        consumer: &mut dyn FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> std::io::Result<()> {
+        consumer(self.0.as_ref())
+    }
+
+    fn size(&self) -> Option<usize> {
+        Some(self.0.as_ref().len())
+    }
+
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.0.as_ref())
+    }
+}
+
+impl<T: Display + ?Sized> Payload for T {
+    fn stream(
+        &self,
+        consumer: &mut dyn FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> std::io::Result<()> {
+        struct Adapter<'a> {
+            consumer: &'a mut dyn FnMut(&[u8]) -> std::io::Result<()>,
+            error: Option<std::io::Error>,
+        }
+
+        impl std::fmt::Write for Adapter<'_> {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                (self.consumer)(s.as_bytes()).map_err(|e| {
+                    self.error = Some(e);
+                    std::fmt::Error
+                })
+            }
+        }
+
+        let mut adapter = Adapter {
+            consumer,
+            error: None,
+        };
+
+        std::fmt::write(&mut adapter, format_args!("{self}")).map_err(|_| {
+            adapter
+                .error
+                .take()
+                .unwrap_or_else(|| std::io::Error::other("formatting failed"))
+        })
+    }
+
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.to_string().into_bytes())
+    }
+}
 
 /// Append to the files given as instances convertible to a
 /// [`std::path::PathBuf`].
-pub trait PathBufLikeAppendix<T>
-where
-    PathBuf: From<T>,
-{
+///
+/// A [`crate::StdioPath`] which is `-` denotes [`std::io::Stdout`], instead.
+pub trait PathBufLikeAppendix<T> {
+    /// Show how appending the data this method is called on would change the
+    /// given destination.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeAppendix::append_dry_run_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_dry_run_loudly(
+        self,
+        destination: T,
+        diff: Diff,
+    ) -> Result<String>;
+
+    /// Show how appending the data this method is called on would change the
+    /// given destination.
+    ///
+    /// The data this method is called on will be rendered and compared with the
+    /// content the given file would have after
+    /// [`crate::PathBufLikeAppendix::append_silently`].  The file will not be
+    /// written to.
+    ///
+    /// The return value is either the changes as a unified diff rendered by
+    /// the given [`crate::Diff`], in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_dry_run_silently(
+        self,
+        destination: T,
+        diff: Diff,
+    ) -> Result<String>;
+
+    /// Show how appending the data this method is called on would change the
+    /// given destination.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeAppendix::append_dry_run_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_dry_run_with<S: Sink>(
+        self,
+        destination: T,
+        diff: Diff,
+        sink: S,
+    ) -> Result<String>;
+
     /// Append the data this method is called on to the given destination.
     ///
     /// This method behaves just like
@@ -34,264 +237,1091 @@ where
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn append_loudly(self, destination: T) -> Result<()>;
 
     /// Append the data this method is called on to the given destination.
     ///
-    /// The data this method is called on will be converted to a [`String`] and
-    /// appended to the given file.  The data therefore needs to implement
-    /// [`ToString`].  The file needs to be convertible to a
+    /// The data this method is called on will be streamed to the given file.
+    /// The data therefore needs to implement [`crate::Payload`].  The file
+    /// needs to be convertible to a
     /// [`std::path::PathBuf`].  The data will be appended at the end of the
     /// file, already existing data will not be changed.  In case that the file
     /// should not already exist, it will be created before writing to it.
     ///
     /// The return value is either the unit type, in case of success, or a
-    /// [`sysexits::ExitCode`] to describe the error cause, otherwise.
+    /// [`crate::Error`] to describe the error cause, otherwise.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn append_silently(self, destination: T) -> Result<()>;
+
+    /// Append the data this method is called on to the given destination.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeAppendix::append_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_with<S: Sink>(self, destination: T, sink: S) -> Result<()>;
 }
 
-impl<P, T: ToString> PathBufLikeAppendix<P> for T
+impl<P, T: Payload> PathBufLikeAppendix<P> for T
 where
     PathBuf: From<P>,
 {
+    fn append_dry_run_loudly(
+        self,
+        destination: P,
+        diff: Diff,
+    ) -> Result<String> {
+        self.append_dry_run_with(destination, diff, StderrSink)
+    }
+
+    fn append_dry_run_silently(
+        self,
+        destination: P,
+        diff: Diff,
+    ) -> Result<String> {
+        self.append_dry_run_with(destination, diff, NullSink)
+    }
+
+    fn append_dry_run_with<S: Sink>(
+        self,
+        destination: P,
+        diff: Diff,
+        mut sink: S,
+    ) -> Result<String> {
+        let path = PathBuf::from(destination);
+
+        dry_run(&path, diff, &self.to_bytes(), true, &mut sink)
+            .inspect_err(|e| sink.report(e))
+    }
+
     fn append_loudly(self, destination: P) -> Result<()> {
+        PathBufLikeAppendix::append_with(self, destination, StderrSink)
+    }
+
+    fn append_silently(self, destination: P) -> Result<()> {
+        PathBufLikeAppendix::append_with(self, destination, NullSink)
+    }
+
+    fn append_with<S: Sink>(self, destination: P, mut sink: S) -> Result<()> {
+        let path = PathBuf::from(destination);
+
         match std::fs::File::options()
             .append(true)
             .create(true)
             .truncate(false)
-            .open(PathBuf::from(destination))
+            .open(&path)
         {
             Err(e) => {
-                eprintln!("{e}");
-                Err(e.into())
+                let e = Error::new(
+                    Operation::Append,
+                    Step::Open,
+                    Some(path),
+                    Cause::Io(e),
+                );
+                sink.report(&e);
+                Err(e)
             }
-            Ok(mut file) => {
-                let bytes = self.to_string().as_bytes().to_vec();
+            Ok(file) => write(
+                Operation::Append,
+                Some(path),
+                file,
+                &self,
+                Retry::new(),
+                sink,
+            ),
+        }
+    }
+}
+
+/// Truncate files given as instances convertible to a [`std::path::PathBuf`].
+///
+/// A [`crate::StdioPath`] which is `-` denotes [`std::io::Stdout`], instead.
+pub trait PathBufLikeTruncation<T> {
+    /// Check whether truncating the given file would change it.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::check_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_loudly(self, destination: T) -> Result<Check>;
+
+    /// Check whether truncating the given file would change it.
+    ///
+    /// The data this method is called on will be rendered and compared with the
+    /// current content of the given file.  The file will not be written to.
+    ///
+    /// The return value is either a [`crate::Check`] to describe whether the
+    /// file is up to date, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  Files which do not exist are
+    /// reported as [`crate::Check::WouldCreate`].
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_silently(self, destination: T) -> Result<Check>;
+
+    /// Check whether truncating the given file would change it.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::check_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_with<S: Sink>(self, destination: T, sink: S) -> Result<Check>;
+
+    /// Atomically replace the given file using the data this method is called
+    /// on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_atomically_silently`] despite
+    /// also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_atomically_loudly(self, destination: T) -> Result<()>;
+
+    /// Atomically replace the given file using the data this method is called
+    /// on.
+    ///
+    /// The data this method is called on will be streamed to a temporary file
+    /// next to the given one.  The temporary file
+    /// will be synchronised and then renamed to the given file such that the
+    /// given file either keeps its original content or holds the new one, even
+    /// in case of a crash.  Afterwards, the directory will be synchronised, as
+    /// well, in order to persist the rename.  The permissions of an already
+    /// existing file will be preserved.  If the given file is a symbolic link,
+    /// the file it points to will be replaced.  In case of an error, the
+    /// temporary file will be removed again.
+    ///
+    /// The return value is either the unit type, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_atomically_silently(self, destination: T) -> Result<()>;
+
+    /// Atomically replace the given file using the data this method is called
+    /// on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_atomically_silently`] despite
+    /// also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_atomically_with<S: Sink>(
+        self,
+        destination: T,
+        sink: S,
+    ) -> Result<()>;
+
+    /// Show how truncating the given file using the data this method is
+    /// called on would change it.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_dry_run_silently`] despite
+    /// also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_dry_run_loudly(
+        self,
+        destination: T,
+        diff: Diff,
+    ) -> Result<String>;
+
+    /// Show how truncating the given file using the data this method is
+    /// called on would change it.
+    ///
+    /// The data this method is called on will be rendered and compared with the
+    /// current content of the given file.  The file will not be written to.
+    ///
+    /// The return value is either the changes as a unified diff rendered by
+    /// the given [`crate::Diff`], in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  If the file is up to date, the
+    /// diff is empty.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_dry_run_silently(
+        self,
+        destination: T,
+        diff: Diff,
+    ) -> Result<String>;
+
+    /// Show how truncating the given file using the data this method is
+    /// called on would change it.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_dry_run_silently`] despite
+    /// also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_dry_run_with<S: Sink>(
+        self,
+        destination: T,
+        diff: Diff,
+        sink: S,
+    ) -> Result<String>;
+
+    /// Truncate the given file using the data this method is called on, if
+    /// its content differs.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_if_changed_silently`] despite
+    /// also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_if_changed_loudly(self, destination: T) -> Result<bool>;
+
+    /// Truncate the given file using the data this method is called on, if
+    /// its content differs.
+    ///
+    /// The data this method is called on will be rendered and compared with
+    /// the current content of the given file.  Only if they differ, the file
+    /// will be truncated by
+    /// [`crate::PathBufLikeTruncation::truncate_silently`].  Otherwise, the
+    /// file will not be touched at all such that its modification time is
+    /// preserved.  Files which do not exist or cannot be read are considered to
+    /// differ, whereby read failures are recorded as warnings by the
+    /// [`crate::Sink`].
+    ///
+    /// The return value is either whether the file was changed, in case of
+    /// success, or a [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_if_changed_silently(self, destination: T) -> Result<bool>;
+
+    /// Truncate the given file using the data this method is called on, if
+    /// its content differs.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_if_changed_silently`] despite
+    /// also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_if_changed_with<S: Sink>(
+        self,
+        destination: T,
+        sink: S,
+    ) -> Result<bool>;
+
+    /// Truncate the given file using the data this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_silently`] despite also
+    /// printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_loudly(self, destination: T) -> Result<()>;
+
+    /// Truncate the given file using the data this method is called on.
+    ///
+    /// The data this method is called on will be streamed to the given file.
+    /// The data therefore needs to implement [`crate::Payload`].  The file
+    /// needs to be convertible to a
+    /// [`std::path::PathBuf`].  The file will be truncated.
+    ///
+    /// The return value is either the unit type, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_silently(self, destination: T) -> Result<()>;
+
+    /// Truncate the given file using the data this method is called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::PathBufLikeTruncation::truncate_silently`] despite also
+    /// reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_with<S: Sink>(self, destination: T, sink: S) -> Result<()>;
+}
+
+impl<P, T: Payload> PathBufLikeTruncation<P> for T
+where
+    PathBuf: From<P>,
+{
+    fn check_loudly(self, destination: P) -> Result<Check> {
+        PathBufLikeTruncation::check_with(self, destination, StderrSink)
+    }
+
+    fn check_silently(self, destination: P) -> Result<Check> {
+        PathBufLikeTruncation::check_with(self, destination, NullSink)
+    }
+
+    fn check_with<S: Sink>(self, destination: P, mut sink: S) -> Result<Check> {
+        let path = PathBuf::from(destination);
+
+        check(&path, &self.to_bytes(), &mut sink)
+            .inspect_err(|e| sink.report(e))
+    }
+
+    fn truncate_atomically_loudly(self, destination: P) -> Result<()> {
+        self.truncate_atomically_with(destination, StderrSink)
+    }
+
+    fn truncate_atomically_silently(self, destination: P) -> Result<()> {
+        self.truncate_atomically_with(destination, NullSink)
+    }
+
+    fn truncate_atomically_with<S: Sink>(
+        self,
+        destination: P,
+        mut sink: S,
+    ) -> Result<()> {
+        let path = PathBuf::from(destination);
+
+        replace(&path, &self)
+            .map(|written| {
+                sink.record(&Event::new(
+                    Operation::Truncate,
+                    Some(path),
+                    written,
+                ));
+            })
+            .inspect_err(|e| sink.report(e))
+    }
+
+    fn truncate_dry_run_loudly(
+        self,
+        destination: P,
+        diff: Diff,
+    ) -> Result<String> {
+        self.truncate_dry_run_with(destination, diff, StderrSink)
+    }
+
+    fn truncate_dry_run_silently(
+        self,
+        destination: P,
+        diff: Diff,
+    ) -> Result<String> {
+        self.truncate_dry_run_with(destination, diff, NullSink)
+    }
+
+    fn truncate_dry_run_with<S: Sink>(
+        self,
+        destination: P,
+        diff: Diff,
+        mut sink: S,
+    ) -> Result<String> {
+        let path = PathBuf::from(destination);
+
+        dry_run(&path, diff, &self.to_bytes(), false, &mut sink)
+            .inspect_err(|e| sink.report(e))
+    }
+
+    fn truncate_if_changed_loudly(self, destination: P) -> Result<bool> {
+        self.truncate_if_changed_with(destination, StderrSink)
+    }
+
+    fn truncate_if_changed_silently(self, destination: P) -> Result<bool> {
+        self.truncate_if_changed_with(destination, NullSink)
+    }
+
+    fn truncate_if_changed_with<S: Sink>(
+        self,
+        destination: P,
+        mut sink: S,
+    ) -> Result<bool> {
+        let path = PathBuf::from(destination);
+        let bytes = self.to_bytes();
+
+        match check(&path, &bytes, NullSink) {
+            Ok(Check::Unchanged) => return Ok(false),
+            Ok(_) => {}
+            Err(e) => sink.record(&Event::warn(
+                Operation::Truncate,
+                Some(path.clone()),
+                format!("treated as changed since {}", e.message()),
+            )),
+        }
+
+        PathBufLikeTruncation::<PathBuf>::truncate_with(
+            Bytes(bytes),
+            path,
+            sink,
+        )
+        .map(|()| true)
+    }
+
+    fn truncate_loudly(self, destination: P) -> Result<()> {
+        PathBufLikeTruncation::truncate_with(self, destination, StderrSink)
+    }
+
+    fn truncate_silently(self, destination: P) -> Result<()> {
+        PathBufLikeTruncation::truncate_with(self, destination, NullSink)
+    }
+
+    fn truncate_with<S: Sink>(self, destination: P, mut sink: S) -> Result<()> {
+        let path = PathBuf::from(destination);
+
+        match std::fs::File::options()
+            .append(false)
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&path)
+        {
+            Err(e) => {
+                let e = Error::new(
+                    Operation::Truncate,
+                    Step::Open,
+                    Some(path),
+                    Cause::Io(e),
+                );
+                sink.report(&e);
+                Err(e)
+            }
+            Ok(file) => write(
+                Operation::Truncate,
+                Some(path),
+                file,
+                &self,
+                Retry::new(),
+                sink,
+            ),
+        }
+    }
+}
+
+/// Append to either destination, depending on the circumstances.
+pub trait OptionAppendix<P, W>
+where
+    W: Write,
+{
+    /// Append to either destination, depending on the circumstances.
+    ///
+    /// This method behaves just like [`crate::OptionAppendix::append_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_loudly(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()>;
+
+    /// Append to either destination, depending on the circumstances.
+    ///
+    /// The data this method is called on will be streamed to either
+    /// destination.  The data therefore needs to implement
+    /// [`crate::Payload`].  If the default destination is [`Some`], the data
+    /// will be appended to it by
+    /// [`crate::PathBufLikeAppendix::append_silently`].  The default
+    /// destination therefore needs to implement [`crate::PathBufLikeAppendix`].
+    /// If the default destination is [`None`], the alternative will be used
+    /// as output stream.  The alternative therefore needs to implement
+    /// [`crate::Writer`].
+    ///
+    /// The return value is either the unit type, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_silently(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()>;
+
+    /// Append to either destination, depending on the circumstances.
+    ///
+    /// This method behaves just like [`crate::OptionAppendix::append_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_with<S: Sink>(
+        self,
+        destination: Option<P>,
+        alternative: W,
+        sink: S,
+    ) -> Result<()>;
+}
+
+impl<P, W: Write, T: Payload + PathBufLikeAppendix<P>> OptionAppendix<P, W>
+    for T
+{
+    fn append_loudly(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()> {
+        OptionAppendix::append_with(self, destination, alternative, StderrSink)
+    }
+
+    fn append_silently(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()> {
+        OptionAppendix::append_with(self, destination, alternative, NullSink)
+    }
+
+    fn append_with<S: Sink>(
+        self,
+        destination: Option<P>,
+        alternative: W,
+        sink: S,
+    ) -> Result<()> {
+        either(
+            self,
+            destination,
+            alternative,
+            sink,
+            PathBufLikeAppendix::append_with,
+        )
+    }
+}
+
+/// Truncate either destination, depending on the circumstances.
+pub trait OptionTruncation<P, W>
+where
+    W: Write,
+{
+    /// Check whether truncating either destination would change it.
+    ///
+    /// This method behaves just like
+    /// [`crate::OptionTruncation::check_silently`] despite also printing error
+    /// messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_loudly(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<Check>;
+
+    /// Check whether truncating either destination would change it.
+    ///
+    /// If the default destination is [`Some`], it will be checked by
+    /// [`crate::PathBufLikeTruncation::check_silently`].  If the default
+    /// destination is [`None`], the data would be written to the alternative
+    /// output stream.  As streams cannot be compared, the result is
+    /// [`crate::Check::WouldModify`], then.  Neither destination will be
+    /// written to.
+    ///
+    /// The return value is either a [`crate::Check`] to describe whether the
+    /// destination is up to date, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_silently(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<Check>;
+
+    /// Check whether truncating either destination would change it.
+    ///
+    /// This method behaves just like
+    /// [`crate::OptionTruncation::check_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn check_with<S: Sink>(
+        self,
+        destination: Option<P>,
+        alternative: W,
+        sink: S,
+    ) -> Result<Check>;
+
+    /// Truncate either destination, depending on the circumstances.
+    ///
+    /// This method behaves just like
+    /// [`crate::OptionTruncation::truncate_silently`] despite also printing
+    /// error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_loudly(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()>;
+
+    /// Truncate either destination, depending on the circumstances.
+    ///
+    /// The data this method is called on will be streamed to either
+    /// destination.  The data therefore needs to implement
+    /// [`crate::Payload`].  If the default destination is [`Some`], it will be
+    /// truncated by [`crate::PathBufLikeTruncation::truncate_silently`].  The
+    /// default destination therefore needs to implement
+    /// [`crate::PathBufLikeTruncation`].  If the default
+    /// destination is [`None`], the alternative will be used as output stream.
+    /// The alternative therefore needs to implement [`crate::Writer`].
+    ///
+    /// The return value is either the unit type, in case of success, or a
+    /// [`crate::Error`] to describe the error cause, otherwise.
+    ///
+    /// Error messages are not written to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_silently(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()>;
+
+    /// Truncate either destination, depending on the circumstances.
+    ///
+    /// This method behaves just like
+    /// [`crate::OptionTruncation::truncate_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_with<S: Sink>(
+        self,
+        destination: Option<P>,
+        alternative: W,
+        sink: S,
+    ) -> Result<()>;
+}
+
+impl<P, W: Write, T: Payload + PathBufLikeTruncation<P>> OptionTruncation<P, W>
+    for T
+{
+    fn check_loudly(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<Check> {
+        OptionTruncation::check_with(self, destination, alternative, StderrSink)
+    }
+
+    fn check_silently(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<Check> {
+        OptionTruncation::check_with(self, destination, alternative, NullSink)
+    }
+
+    fn check_with<S: Sink>(
+        self,
+        destination: Option<P>,
+        _: W,
+        sink: S,
+    ) -> Result<Check> {
+        destination.map_or(Ok(Check::WouldModify), |p| {
+            PathBufLikeTruncation::check_with(self, p, sink)
+        })
+    }
+
+    fn truncate_loudly(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()> {
+        OptionTruncation::truncate_with(
+            self,
+            destination,
+            alternative,
+            StderrSink,
+        )
+    }
+
+    fn truncate_silently(
+        self,
+        destination: Option<P>,
+        alternative: W,
+    ) -> Result<()> {
+        OptionTruncation::truncate_with(
+            self,
+            destination,
+            alternative,
+            NullSink,
+        )
+    }
+
+    fn truncate_with<S: Sink>(
+        self,
+        destination: Option<P>,
+        alternative: W,
+        sink: S,
+    ) -> Result<()> {
+        either(
+            self,
+            destination,
+            alternative,
+            sink,
+            PathBufLikeTruncation::truncate_with,
+        )
+    }
+}
+
+/// How to proceed when writing to one of several destinations fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Policy {
+    /// Continue with the remaining destinations and collect the errors.
+    Collect,
+
+    /// Stop at the first error and return it.
+    Stop,
+}
+
+/// The policy for retrying writes which did not make any progress.
+///
+/// Writes failing with [`std::io::ErrorKind::Interrupted`] are retried
+/// immediately, those failing with [`std::io::ErrorKind::WouldBlock`] after
+/// the configured delay.  Once the configured number of consecutive retries
+/// failed, the last error will be reported.  Any progress resets the count.
+/// Successful writes which needed retries are recorded as warnings by the
+/// [`crate::Sink`].
+///
+/// All writers of this crate use [`crate::Retry::new`] unless stated
+/// otherwise.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Retry {
+    attempts: usize,
+    delay: Duration,
+}
+
+impl Retry {
+    /// Set the number of consecutive retries.
+    ///
+    /// The default is 16.  Zero disables retrying.
+    #[must_use]
+    pub const fn attempts(mut self, attempts: usize) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// Set the delay before retrying a blocked write.
+    ///
+    /// The default is one millisecond.
+    #[must_use]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
 
-                match file.write(&bytes) {
-                    Err(e) => {
-                        eprintln!("{e}");
-                        Err(e.into())
-                    }
-                    Ok(n) => {
-                        if n == bytes.len() {
-                            Ok(())
-                        } else {
-                            eprintln!(
-                                "Creating an exact copy was not possible."
-                            );
-                            Err(sysexits::ExitCode::IoErr)
-                        }
-                    }
-                }
-            }
+    /// Create a new instance with the default settings.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            attempts: 16,
+            delay: Duration::from_millis(1),
         }
     }
+}
 
-    fn append_silently(self, destination: P) -> Result<()> {
-        match std::fs::File::options()
-            .append(true)
-            .create(true)
-            .truncate(false)
-            .open(PathBuf::from(destination))
-        {
-            Err(e) => Err(e.into()),
-            Ok(mut file) => {
-                let bytes = self.to_string().as_bytes().to_vec();
-
-                match file.write(&bytes) {
-                    Err(e) => Err(e.into()),
-                    Ok(n) => {
-                        if n == bytes.len() {
-                            Ok(())
-                        } else {
-                            Err(sysexits::ExitCode::IoErr)
-                        }
-                    }
-                }
-            }
-        }
+impl Default for Retry {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-/// Truncate files given as instances convertible to a [`std::path::PathBuf`].
-pub trait PathBufLikeTruncation<T>
+/// Append to all destinations given as a list of instances convertible to a
+/// [`std::path::PathBuf`] or of [`crate::StdioPath`]s.
+pub trait VectorAppendix<P, W>
 where
-    PathBuf: From<T>,
+    W: Write,
 {
-    /// Truncate the given file using the data this method is called on.
+    /// Append the data this method is called on to each of the given
+    /// destinations.
     ///
     /// This method behaves just like
-    /// [`crate::PathBufLikeTruncation::truncate_silently`] despite also
-    /// printing error messages to [`std::io::Stderr`].
+    /// [`crate::VectorAppendix::append_silently`] despite also printing error
+    /// messages to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
-    fn truncate_loudly(self, destination: T) -> Result<()>;
+    /// See [`crate::Error`].
+    fn append_loudly(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+    ) -> Result<Vec<Error>>;
 
-    /// Truncate the given file using the data this method is called on.
+    /// Append the data this method is called on to each of the given
+    /// destinations.
     ///
-    /// The data this method is called on will be converted to a [`String`] and
-    /// written to the given file.  The data therefore needs to implement
-    /// [`ToString`].  The file needs to be convertible to a
-    /// [`std::path::PathBuf`].  The file will be truncated.
+    /// The data this method is called on needs to implement
+    /// [`crate::Payload`].  If the given list of destinations is not empty,
+    /// the data will be rendered once and appended to each of them by
+    /// [`crate::PathBufLikeAppendix::append_silently`].  In case the list is
+    /// empty, the data will be written to the alternative output stream by
+    /// [`crate::Writer::write_silently`], instead.
     ///
-    /// The return value is either the unit type, in case of success, or a
-    /// [`sysexits::ExitCode`] to describe the error cause, otherwise.
+    /// The return value is either the errors of the destinations which could
+    /// not be written to, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  Using [`crate::Policy::Stop`],
+    /// the first error will be returned and the remaining destinations will
+    /// not be touched.  Using [`crate::Policy::Collect`], all destinations
+    /// will be written to and their errors will be collected.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
-    fn truncate_silently(self, destination: T) -> Result<()>;
+    /// See [`crate::Error`].
+    fn append_silently(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+    ) -> Result<Vec<Error>>;
+
+    /// Append the data this method is called on to each of the given
+    /// destinations.
+    ///
+    /// This method behaves just like
+    /// [`crate::VectorAppendix::append_silently`] despite also reporting error
+    /// messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn append_with<S: Sink>(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+        sink: S,
+    ) -> Result<Vec<Error>>;
 }
 
-impl<P, T: ToString> PathBufLikeTruncation<P> for T
+impl<P: Clone, W: Write, T: Payload> VectorAppendix<P, W> for T
 where
-    PathBuf: From<P>,
+    for<'a> Bytes<&'a [u8]>: PathBufLikeAppendix<P>,
 {
-    fn truncate_loudly(self, destination: P) -> Result<()> {
-        match std::fs::File::options()
-            .append(false)
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(PathBuf::from(destination))
-        {
-            Err(e) => {
-                eprintln!("{e}");
-                Err(e.into())
-            }
-            Ok(mut file) => {
-                let bytes = self.to_string().as_bytes().to_vec();
+    fn append_loudly(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+    ) -> Result<Vec<Error>> {
+        VectorAppendix::append_with(
+            self,
+            destinations,
+            alternative,
+            policy,
+            StderrSink,
+        )
+    }
 
-                match file.write(&bytes) {
-                    Err(e) => {
-                        eprintln!("{e}");
-                        Err(e.into())
-                    }
-                    Ok(n) => {
-                        if n == bytes.len() {
-                            Ok(())
-                        } else {
-                            eprintln!(
-                                "Creating an exact copy was not possible."
-                            );
-                            Err(sysexits::ExitCode::IoErr)
-                        }
-                    }
-                }
-            }
-        }
+    fn append_silently(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+    ) -> Result<Vec<Error>> {
+        VectorAppendix::append_with(
+            self,
+            destinations,
+            alternative,
+            policy,
+            NullSink,
+        )
     }
 
-    fn truncate_silently(self, destination: P) -> Result<()> {
-        match std::fs::File::options()
-            .append(false)
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(PathBuf::from(destination))
-        {
-            Err(e) => Err(e.into()),
-            Ok(mut file) => {
-                let bytes = self.to_string().as_bytes().to_vec();
-
-                match file.write(&bytes) {
-                    Err(e) => Err(e.into()),
-                    Ok(n) => {
-                        if n == bytes.len() {
-                            Ok(())
-                        } else {
-                            Err(sysexits::ExitCode::IoErr)
-                        }
-                    }
-                }
-            }
-        }
+    fn append_with<S: Sink>(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+        sink: S,
+    ) -> Result<Vec<Error>> {
+        tee(
+            destinations,
+            self,
+            alternative,
+            policy,
+            sink,
+            |bytes, path, sink| {
+                PathBufLikeAppendix::append_with(bytes, path, sink)
+            },
+        )
     }
 }
 
-/// Truncate either destination, depending on the circumstances.
-pub trait OptionTruncation<P, W>
+/// Truncate all destinations given as a list of instances convertible to a
+/// [`std::path::PathBuf`] or of [`crate::StdioPath`]s.
+pub trait VectorTruncation<P, W>
 where
-    PathBuf: From<P>,
     W: Write,
 {
-    /// Truncate either destination, depending on the circumstances.
+    /// Truncate each of the given destinations using the data this method is
+    /// called on.
     ///
     /// This method behaves just like
-    /// [`crate::OptionTruncation::truncate_silently`] despite also printing
+    /// [`crate::VectorTruncation::truncate_silently`] despite also printing
     /// error messages to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn truncate_loudly(
         self,
-        destination: Option<P>,
+        destinations: &[P],
         alternative: W,
-    ) -> Result<()>;
+        policy: Policy,
+    ) -> Result<Vec<Error>>;
 
-    /// Truncate either destination, depending on the circumstances.
+    /// Truncate each of the given destinations using the data this method is
+    /// called on.
     ///
-    /// The data this method is called on will be converted to a [`String`] and
-    /// written to either source.  The data therefore needs to implement
-    /// [`ToString`].  If the default destination is [`Some`], it will be
-    /// truncated by [`crate::PathBufLikeTruncation::truncate_silently`].  The
-    /// default destination therefore needs to implement
-    /// [`crate::PathBufLikeTruncation`].  If the default
-    /// destination is [`None`], the alternative will be used as output stream.
-    /// The alternative therefore needs to implement [`crate::Writer`].
+    /// The data this method is called on needs to implement
+    /// [`crate::Payload`].  If the given list of destinations is not empty,
+    /// the data will be rendered once and written to each of them by
+    /// [`crate::PathBufLikeTruncation::truncate_silently`].  In case the
+    /// list is empty, the data will be written to the alternative output
+    /// stream by [`crate::Writer::write_silently`], instead.
     ///
-    /// The return value is either the unit type, in case of success, or a
-    /// [`sysexits::ExitCode`] to describe the error cause, otherwise.
+    /// The return value is either the errors of the destinations which could
+    /// not be written to, in case of success, or a [`crate::Error`] to
+    /// describe the error cause, otherwise.  Using [`crate::Policy::Stop`],
+    /// the first error will be returned and the remaining destinations will
+    /// not be touched.  Using [`crate::Policy::Collect`], all destinations
+    /// will be written to and their errors will be collected.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn truncate_silently(
         self,
-        destination: Option<P>,
+        destinations: &[P],
         alternative: W,
-    ) -> Result<()>;
+        policy: Policy,
+    ) -> Result<Vec<Error>>;
+
+    /// Truncate each of the given destinations using the data this method is
+    /// called on.
+    ///
+    /// This method behaves just like
+    /// [`crate::VectorTruncation::truncate_silently`] despite also reporting
+    /// error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn truncate_with<S: Sink>(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+        sink: S,
+    ) -> Result<Vec<Error>>;
 }
 
-impl<P, W: Write, T: ToString> OptionTruncation<P, W> for T
+impl<P: Clone, W: Write, T: Payload> VectorTruncation<P, W> for T
 where
-    PathBuf: From<P>,
+    for<'a> Bytes<&'a [u8]>: PathBufLikeTruncation<P>,
 {
     fn truncate_loudly(
         self,
-        destination: Option<P>,
+        destinations: &[P],
         alternative: W,
-    ) -> Result<()> {
-        match destination {
-            Some(p) => PathBufLikeTruncation::truncate_loudly(self, p),
-            None => self.write_loudly(alternative),
-        }
+        policy: Policy,
+    ) -> Result<Vec<Error>> {
+        VectorTruncation::truncate_with(
+            self,
+            destinations,
+            alternative,
+            policy,
+            StderrSink,
+        )
     }
 
     fn truncate_silently(
         self,
-        destination: Option<P>,
+        destinations: &[P],
         alternative: W,
-    ) -> Result<()> {
-        match destination {
-            Some(p) => PathBufLikeTruncation::truncate_silently(self, p),
-            None => self.write_silently(alternative),
-        }
+        policy: Policy,
+    ) -> Result<Vec<Error>> {
+        VectorTruncation::truncate_with(
+            self,
+            destinations,
+            alternative,
+            policy,
+            NullSink,
+        )
+    }
+
+    fn truncate_with<S: Sink>(
+        self,
+        destinations: &[P],
+        alternative: W,
+        policy: Policy,
+        sink: S,
+    ) -> Result<Vec<Error>> {
+        tee(
+            destinations,
+            self,
+            alternative,
+            policy,
+            sink,
+            |bytes, path, sink| {
+                PathBufLikeTruncation::truncate_with(bytes, path, sink)
+            },
+        )
     }
 }
 
@@ -307,60 +1337,576 @@ where
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn write_loudly(self, destination: T) -> Result<()>;
 
     /// Write the data this method is called on to the given destination.
     ///
-    /// The data this method is called on will be converted to a [`String`] and
-    /// written to the given destination.  The data therefore needs to implement
-    /// [`ToString`].  The destination needs to implement [`std::io::Write`].
+    /// This method behaves just like [`crate::Writer::write_retrying_silently`]
+    /// despite also printing error messages to [`std::io::Stderr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn write_retrying_loudly(self, destination: T, retry: Retry) -> Result<()>;
+
+    /// Write the data this method is called on to the given destination.
+    ///
+    /// This method behaves just like [`crate::Writer::write_silently`] but
+    /// retries failed writes according to the given [`crate::Retry`] policy.
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn write_retrying_silently(
+        self,
+        destination: T,
+        retry: Retry,
+    ) -> Result<()>;
+
+    /// Write the data this method is called on to the given destination.
+    ///
+    /// This method behaves just like [`crate::Writer::write_retrying_silently`]
+    /// despite also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn write_retrying_with<S: Sink>(
+        self,
+        destination: T,
+        retry: Retry,
+        sink: S,
+    ) -> Result<()>;
+
+    /// Write the data this method is called on to the given destination.
+    ///
+    /// The data this method is called on will be streamed to the given
+    /// destination.  The data therefore needs to implement [`crate::Payload`].
+    /// The destination needs to implement [`std::io::Write`].
+    /// Short writes are continued until all bytes are written, failed writes
+    /// are retried according to [`crate::Retry::new`].
     ///
     /// The return value is either the unit type, in case of success, or a
-    /// [`sysexits::ExitCode`] to describe the error cause, otherwise.
+    /// [`crate::Error`] to describe the error cause, otherwise.
     ///
     /// Error messages are not written to [`std::io::Stderr`].
     ///
     /// # Errors
     ///
-    /// See [`sysexits::ExitCode`].
+    /// See [`crate::Error`].
     fn write_silently(self, destination: T) -> Result<()>;
+
+    /// Write the data this method is called on to the given destination.
+    ///
+    /// This method behaves just like [`crate::Writer::write_silently`] despite
+    /// also reporting error messages to the given [`crate::Sink`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Error`].
+    fn write_with<S: Sink>(self, destination: T, sink: S) -> Result<()>;
 }
 
-impl<T: ToString, W: Write> Writer<W> for T {
-    fn write_loudly(self, mut destination: W) -> Result<()> {
-        let bytes = self.to_string().as_bytes().to_vec();
+impl<T: Payload, W: Write> Writer<W> for T {
+    fn write_loudly(self, destination: W) -> Result<()> {
+        self.write_with(destination, StderrSink)
+    }
+
+    fn write_silently(self, destination: W) -> Result<()> {
+        self.write_with(destination, NullSink)
+    }
 
-        match destination.write(&bytes) {
-            Err(e) => {
-                eprintln!("{e}");
-                Err(e.into())
+    fn write_retrying_loudly(self, destination: W, retry: Retry) -> Result<()> {
+        self.write_retrying_with(destination, retry, StderrSink)
+    }
+
+    fn write_retrying_silently(
+        self,
+        destination: W,
+        retry: Retry,
+    ) -> Result<()> {
+        self.write_retrying_with(destination, retry, NullSink)
+    }
+
+    fn write_retrying_with<S: Sink>(
+        self,
+        destination: W,
+        retry: Retry,
+        sink: S,
+    ) -> Result<()> {
+        write(Operation::Write, None, destination, &self, retry, sink)
+    }
+
+    fn write_with<S: Sink>(self, destination: W, sink: S) -> Result<()> {
+        self.write_retrying_with(destination, Retry::new(), sink)
+    }
+}
+
+fn check<S: Sink>(path: &Path, bytes: &[u8], sink: S) -> Result<Check> {
+    Ok(match current(path, sink)? {
+        None => Check::WouldCreate,
+        Some(content) if content == bytes => Check::Unchanged,
+        Some(_) => Check::WouldModify,
+    })
+}
+
+fn current<S: Sink>(path: &Path, mut sink: S) -> Result<Option<Vec<u8>>> {
+    let error = |step| {
+        move |e| {
+            Error::new(
+                Operation::Read,
+                step,
+                Some(path.to_path_buf()),
+                Cause::Io(e),
+            )
+        }
+    };
+
+    let mut content = Vec::new();
+
+    match std::fs::File::open(path) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(error(Step::Open)(e)),
+        Ok(mut file) => {
+            file.read_to_end(&mut content).map_err(error(Step::Read))?;
+        }
+    }
+
+    sink.record(&Event::new(
+        Operation::Read,
+        Some(path.to_path_buf()),
+        content.len(),
+    ));
+
+    Ok(Some(content))
+}
+
+fn dry_run<S: Sink>(
+    path: &Path,
+    diff: Diff,
+    data: &[u8],
+    append: bool,
+    sink: S,
+) -> Result<String> {
+    let original = current(path, sink)?
+        .map(|content| {
+            String::from_utf8(content).map_err(|e| {
+                Error::new(
+                    Operation::Read,
+                    Step::Read,
+                    Some(path.to_path_buf()),
+                    Cause::Io(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        e,
+                    )),
+                )
+            })
+        })
+        .transpose()?;
+
+    let data = String::from_utf8_lossy(data);
+    let modified = if append {
+        original.clone().unwrap_or_default() + &data
+    } else {
+        data.into_owned()
+    };
+
+    Ok(diff.unified(path, original.as_deref(), &modified))
+}
+
+fn either<F, P, S: Sink, T: Payload, W: Write>(
+    data: T,
+    destination: Option<P>,
+    alternative: W,
+    sink: S,
+    file: F,
+) -> Result<()>
+where
+    F: FnOnce(T, P, S) -> Result<()>,
+{
+    match destination {
+        Some(p) => file(data, p, sink),
+        None => data.write_with(alternative, sink),
+    }
+}
+
+fn fill<P: Payload + ?Sized>(
+    file: &std::fs::File,
+    path: &Path,
+    permissions: Option<std::fs::Permissions>,
+    payload: &P,
+) -> Result<usize> {
+    let error = |e| {
+        Error::new(
+            Operation::Truncate,
+            Step::Write,
+            Some(path.to_path_buf()),
+            Cause::Io(e),
+        )
+    };
+
+    if let Some(permissions) = permissions {
+        file.set_permissions(permissions).map_err(error)?;
+    }
+
+    let written = put(
+        Operation::Truncate,
+        Some(path),
+        file,
+        payload,
+        Retry::new(),
+        &mut NullSink,
+    )?;
+
+    file.sync_all().map_err(error).map(|()| written)
+}
+
+fn put<P: Payload + ?Sized, W: Write>(
+    operation: Operation,
+    path: Option<&Path>,
+    destination: W,
+    payload: &P,
+    retry: Retry,
+    sink: &mut dyn Sink,
+) -> Result<usize> {
+    let mut stream = Stream {
+        buffer: Vec::new(),
+        destination,
+        failure: None,
+        retried: 0,
+        retry,
+        written: 0,
+    };
+
+    let mut result = payload.stream(&mut |bytes| stream.push(bytes));
+    let complete = result.is_ok();
+
+    if complete {
+        result = stream.flush();
+    }
+
+    if stream.retried > 0 {
+        sink.record(&Event::warn(
+            operation,
+            path.map(Path::to_path_buf),
+            format!("retried {} interrupted or blocked writes", stream.retried),
+        ));
+    }
+
+    result
+        .map_err(|e| {
+            let (error, pending) = stream
+                .failure
+                .take()
+                .unwrap_or((Some(e), stream.buffer.len()));
+            let expected = payload
+                .size()
+                .or_else(|| complete.then_some(stream.written + pending));
+
+            Error::new(
+                operation,
+                Step::Write,
+                path.map(Path::to_path_buf),
+                match error {
+                    None => Cause::PartialWrite {
+                        expected,
+                        written: stream.written,
+                    },
+                    Some(e) if stream.written == 0 => Cause::Io(e),
+                    Some(e) => Cause::Incomplete {
+                        error: e,
+                        expected,
+                        written: stream.written,
+                    },
+                },
+            )
+        })
+        .map(|()| stream.written)
+}
+
+fn replace<P: Payload + ?Sized>(path: &Path, payload: &P) -> Result<usize> {
+    Staged::new(path, payload)?.commit()
+}
+
+/// A file which was written next to its destination but not renamed, yet.
+///
+/// Staging several files before committing any of them keeps the
+/// destinations untouched as long as any of the writes fails.  Staged files
+/// which are not committed are removed when they are dropped.
+pub(crate) struct Staged {
+    directory: PathBuf,
+    path: PathBuf,
+    target: PathBuf,
+    temporary: Option<PathBuf>,
+    written: usize,
+}
+
+impl Staged {
+    /// Rename the staged file to its destination.
+    pub fn commit(mut self) -> Result<usize> {
+        let error = |step| {
+            let path = self.path.clone();
+            move |e| {
+                Error::new(Operation::Truncate, step, Some(path), Cause::Io(e))
             }
-            Ok(n) => {
-                if n == bytes.len() {
-                    Ok(())
-                } else {
-                    eprintln!("Creating an exact copy was not possible.");
-                    Err(sysexits::ExitCode::IoErr)
-                }
+        };
+
+        if let Some(temporary) = self.temporary.take() {
+            if let Err(e) = std::fs::rename(&temporary, &self.target) {
+                let _ = std::fs::remove_file(&temporary);
+                return Err(error(Step::Rename)(e));
+            }
+        }
+
+        synchronise(&self.directory)
+            .map(|()| self.written)
+            .map_err(error(Step::Synchronise))
+    }
+
+    /// Write the given payload to a temporary file next to the given path.
+    pub fn new<P: Payload + ?Sized>(path: &Path, payload: &P) -> Result<Self> {
+        let error = |step| {
+            move |e| {
+                Error::new(
+                    Operation::Truncate,
+                    step,
+                    Some(path.to_path_buf()),
+                    Cause::Io(e),
+                )
+            }
+        };
+
+        let (target, permissions) = match std::fs::metadata(path) {
+            Ok(m) if m.is_dir() => {
+                return Err(error(Step::Open)(std::io::Error::from(
+                    std::io::ErrorKind::IsADirectory,
+                )));
             }
+            Ok(m) => (
+                std::fs::canonicalize(path).map_err(error(Step::Open))?,
+                Some(m.permissions()),
+            ),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                (path.to_path_buf(), None)
+            }
+            Err(e) => return Err(error(Step::Open)(e)),
+        };
+
+        let directory = match target.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let (temporary, file) =
+            temporary(&directory, &target).map_err(error(Step::Open))?;
+        let mut staged = Self {
+            directory,
+            path: path.to_path_buf(),
+            target,
+            temporary: Some(temporary),
+            written: 0,
+        };
+
+        staged.written = fill(&file, path, permissions, payload)?;
+        Ok(staged)
+    }
+
+    /// The path this file will be renamed to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for Staged {
+    fn drop(&mut self) {
+        if let Some(temporary) = self.temporary.take() {
+            let _ = std::fs::remove_file(temporary);
+        }
+    }
+}
+
+/// The buffer through which payloads are passed to their destination.
+struct Stream<W: Write> {
+    buffer: Vec<u8>,
+    destination: W,
+    failure: Option<(Option<std::io::Error>, usize)>,
+    retried: usize,
+    retry: Retry,
+    written: usize,
+}
+
+impl<W: Write> Stream<W> {
+    const CAPACITY: usize = 8192;
+
+    fn fail(
+        &mut self,
+        error: Option<std::io::Error>,
+        remaining: usize,
+    ) -> std::io::Error {
+        self.failure = Some((error, remaining));
+        std::io::Error::from(ErrorKind::Other)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        let buffer = std::mem::take(&mut self.buffer);
+        let result = self.send(&buffer);
+
+        self.buffer = buffer;
+        self.buffer.clear();
+        result
+    }
+
+    fn push(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        if self.buffer.len() + bytes.len() > Self::CAPACITY {
+            self.flush()?;
+        }
+
+        if bytes.len() > Self::CAPACITY {
+            self.send(bytes)
+        } else {
+            self.buffer.extend_from_slice(bytes);
+            Ok(())
         }
     }
 
-    fn write_silently(self, mut destination: W) -> Result<()> {
-        let bytes = self.to_string().as_bytes().to_vec();
+    fn send(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        let mut failures = 0;
+        let mut sent = 0;
+
+        while sent < bytes.len() {
+            match self.destination.write(&bytes[sent..]) {
+                Ok(0) => return Err(self.fail(None, bytes.len() - sent)),
+                Ok(n) => {
+                    failures = 0;
+                    sent += n;
+                    self.written += n;
+                }
+                Err(e)
+                    if failures < self.retry.attempts
+                        && matches!(
+                            e.kind(),
+                            ErrorKind::Interrupted | ErrorKind::WouldBlock
+                        ) =>
+                {
+                    failures += 1;
+                    self.retried += 1;
 
-        match destination.write(&bytes) {
-            Err(e) => Err(e.into()),
-            Ok(n) => {
-                if n == bytes.len() {
-                    Ok(())
-                } else {
-                    Err(sysexits::ExitCode::IoErr)
+                    if e.kind() == ErrorKind::WouldBlock {
+                        std::thread::sleep(self.retry.delay);
+                    }
                 }
+                Err(e) => return Err(self.fail(Some(e), bytes.len() - sent)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn synchronise(directory: &Path) -> std::io::Result<()> {
+    std::fs::File::open(directory)?.sync_all()
+}
+
+#[cfg(not(unix))]
+fn synchronise(_: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+fn temporary(
+    directory: &Path,
+    target: &Path,
+) -> std::io::Result<(PathBuf, std::fs::File)> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let name = target
+        .file_name()
+        .map_or_else(String::new, |n| n.to_string_lossy().into_owned());
+
+    loop {
+        let path = directory.join(format!(
+            ".{name}.{}.{}.tmp",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        match std::fs::File::options()
+            .create_new(true)
+            .write(true)
+            .open(&path)
+        {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn tee<F, P: Clone, S: Sink, T: Payload, W: Write>(
+    paths: &[P],
+    data: T,
+    alternative: W,
+    policy: Policy,
+    mut sink: S,
+    mut file: F,
+) -> Result<Vec<Error>>
+where
+    F: FnMut(Bytes<&[u8]>, P, &mut S) -> Result<()>,
+{
+    if paths.is_empty() {
+        return data.write_with(alternative, sink).map(|()| Vec::new());
+    }
+
+    let bytes = data.to_bytes();
+    let mut errors = Vec::new();
+
+    for path in paths {
+        if let Err(e) = file(Bytes(&bytes), path.clone(), &mut sink) {
+            match policy {
+                Policy::Collect => errors.push(e),
+                Policy::Stop => return Err(e),
             }
         }
     }
+
+    Ok(errors)
+}
+
+/// Interpret the given payload as UTF-8 text.
+pub(crate) fn text<P: Payload + ?Sized>(
+    payload: &P,
+    operation: Operation,
+) -> Result<String> {
+    String::from_utf8(payload.to_bytes().into_owned()).map_err(|e| {
+        Error::new(
+            operation,
+            Step::Parse,
+            None,
+            Cause::Io(std::io::Error::new(ErrorKind::InvalidData, e)),
+        )
+    })
+}
+
+/// Stream the given payload to the given destination and record the result.
+pub(crate) fn write<P: Payload + ?Sized, S: Sink, W: Write>(
+    operation: Operation,
+    path: Option<PathBuf>,
+    destination: W,
+    payload: &P,
+    retry: Retry,
+    mut sink: S,
+) -> Result<()> {
+    put(
+        operation,
+        path.as_deref(),
+        destination,
+        payload,
+        retry,
+        &mut sink,
+    )
+    .map(|written| sink.record(&Event::new(operation, path, written)))
+    .inspect_err(|e| sink.report(e))
 }
 
 /******************************************************************************/
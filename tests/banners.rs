@@ -0,0 +1,161 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod banner {
+    use aeruginous_io::{Banner, BannerStyle, Cause, Language};
+
+    fn reason(input: &str) -> (usize, usize, String) {
+        match Banner::parse(input, &BannerStyle::new())
+            .unwrap_err()
+            .cause()
+        {
+            Cause::Parse {
+                line,
+                column,
+                reason,
+            } => (*line, *column, reason.clone()),
+            _ => panic!("unexpected cause"),
+        }
+    }
+
+    #[test]
+    fn hash() {
+        let style = BannerStyle::new()
+            .border('#')
+            .fill('=')
+            .language(Language::Hash)
+            .width(20);
+        let banner = Banner::new(Some("T"), "a\n");
+        let rendered = banner.render(&style);
+
+        assert_eq!(
+            rendered,
+            "# /====== T =======\\\n\
+             # #                #\n\
+             # #  a             #\n\
+             # #                #\n\
+             # \\================/\n"
+        );
+        assert_eq!(Banner::parse(&rendered, &style).unwrap(), banner);
+    }
+
+    #[test]
+    fn license() {
+        let header = std::fs::read_to_string("src/lib.rs")
+            .unwrap()
+            .lines()
+            .take(18)
+            .flat_map(|line| [line, "\n"])
+            .collect::<String>();
+        let banner = Banner::parse(&header, &BannerStyle::new()).unwrap();
+
+        assert_eq!(banner.title(), Some("GNU General Public License 3.0"));
+        assert!(banner
+            .text()
+            .starts_with("Copyright (C) 2024 Kevin Matthes\n\n"));
+        assert!(banner
+            .text()
+            .ends_with("<https://www.gnu.org/licenses/>.\n"));
+        assert_eq!(banner.render(&BannerStyle::new()), header);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            reason("// text\n"),
+            (1, 1, "expected top border starting with '/*'".to_string())
+        );
+        assert_eq!(
+            reason("/****\\\n|  a  |\n  b\n"),
+            (3, 1, "expected side borders '|'".to_string())
+        );
+        assert_eq!(
+            reason("/****\\\n|  a  |\n"),
+            (3, 1, "expected bottom border".to_string())
+        );
+    }
+
+    #[test]
+    fn untitled() {
+        let banner = Banner::new(None, "x\n");
+
+        assert_eq!(
+            banner.render(&BannerStyle::new().width(12)),
+            "/**********\\\n|          |\n|  x       |\n|          |\n\\**********/\n"
+        );
+        assert_eq!(
+            banner.render(&BannerStyle::new().language(Language::Html).width(12)),
+            "<!--*******\\\n|          |\n|  x       |\n|          |\n\\********-->\n"
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let banner = Banner::new(None, "aaa bbb  ccc dddddddddd e\n");
+        let rendered = banner.render(&BannerStyle::new().width(14));
+
+        assert_eq!(
+            rendered,
+            "/************\\\n\
+             |            |\n\
+             |  aaa bbb   |\n\
+             |  ccc       |\n\
+             |  dddddddddd|\n\
+             |  e         |\n\
+             |            |\n\
+             \\************/\n"
+        );
+        assert_eq!(
+            Banner::parse(&rendered, &BannerStyle::new())
+                .unwrap()
+                .text(),
+            "aaa bbb\nccc\ndddddddddd\ne\n"
+        );
+    }
+}
+
+mod banner_reader {
+    use aeruginous_io::{BannerReader, BannerStyle, BufferSink, Cause};
+
+    #[test]
+    fn malformed() {
+        let mut sink = BufferSink::default();
+        let e = "Cargo.toml"
+            .read_banner_with(&BannerStyle::new(), &mut sink)
+            .unwrap_err();
+
+        assert!(matches!(e.cause(), Cause::Parse { line: 1, .. }));
+        assert_eq!(e.path(), Some(std::path::Path::new("Cargo.toml")));
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn source_files() {
+        for file in ["src/lib.rs", "src/banners.rs", "tests/banners.rs"] {
+            assert_eq!(
+                file.read_banner_silently(&BannerStyle::new())
+                    .unwrap()
+                    .title(),
+                Some("GNU General Public License 3.0")
+            );
+        }
+    }
+}
+
+/******************************************************************************/
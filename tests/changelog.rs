@@ -0,0 +1,246 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod changelog {
+    use aeruginous_io::{
+        Cause, Changelog, Fragment, PathBufLikeReader, PathBufLikeTruncation,
+        Section, Version,
+    };
+
+    fn reason(input: &str) -> (usize, usize, String) {
+        match Changelog::parse(input).unwrap_err().cause() {
+            Cause::Parse {
+                line,
+                column,
+                reason,
+            } => (*line, *column, reason.clone()),
+            _ => panic!("unexpected cause"),
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            reason("(\n  references: {},\n  introduction: None\n  sections: [],\n)"),
+            (4, 3, "expected ',' or ')', found 's'".to_string())
+        );
+        assert_eq!(
+            reason("(\n  references: {},\n  sections: [],\n)"),
+            (1, 1, "missing field 'introduction'".to_string())
+        );
+        assert_eq!(
+            reason("(references: {}, introduction: 1, sections: [])"),
+            (1, 32, "expected 'Some' or 'None'".to_string())
+        );
+        assert_eq!(
+            reason("(references: {\"a\": \"b}, introduction: None)"),
+            (1, 20, "unterminated string".to_string())
+        );
+        assert_eq!(
+            reason(&"[".repeat(100_000)),
+            (1, 129, "values are nested deeper than 128".to_string())
+        );
+    }
+
+    #[test]
+    fn prepend() {
+        let f = "changelog_prepend.ron";
+        let mut fragment = Fragment::new();
+        let mut changelog = Changelog::new(
+            Some("Changes.".to_string()),
+            vec![("a".to_string(), "b".to_string())],
+        );
+
+        fragment.add("Added", "Changelog");
+        changelog.prepend(Section::new(
+            Version::new(0, 9, 0),
+            "2026-10-18T12:00:00Z",
+            fragment,
+        ));
+
+        assert!(changelog.clone().truncate_silently(f).is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "(
+  references: {
+    \"a\": \"b\",
+  },
+  introduction: Some(\"Changes.\"),
+  sections: [
+    (
+      references: {},
+      version: (
+        major: 0,
+        minor: 9,
+        patch: 0,
+      ),
+      released: \"2026-10-18T12:00:00Z\",
+      introduction: None,
+      changes: (
+        references: {},
+        changes: {
+          \"Added\": [
+            \"Changelog\",
+          ],
+        },
+      ),
+    ),
+  ],
+)
+"
+        );
+        assert_eq!(
+            Changelog::parse(&f.read_silently().unwrap()).unwrap(),
+            changelog
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn round_trip() {
+        let content = "CHANGELOG.ron".read_silently().unwrap();
+        let changelog = Changelog::parse(&content).unwrap();
+
+        assert_eq!(changelog.to_string(), content);
+        assert_eq!(changelog.sections()[0].version(), Version::new(0, 8, 1));
+        assert_eq!(
+            changelog.sections()[0].released(),
+            "2024-06-06T11:49:35.668876508Z"
+        );
+        assert!(changelog.introduction().unwrap().contains("\n\nThe format"));
+        assert_eq!(changelog.references()[0].0, "Keep a Changelog");
+    }
+}
+
+mod changelog_reader {
+    use aeruginous_io::{BufferSink, Cause, ChangelogReader};
+
+    #[test]
+    fn fragments() {
+        for entry in std::fs::read_dir("changelog.d").unwrap() {
+            let path = entry.unwrap().path();
+            let fragment = path.read_fragment_silently().unwrap();
+
+            assert_eq!(
+                fragment.to_string(),
+                std::fs::read_to_string(&path).unwrap()
+            );
+            assert!(!fragment.changes().is_empty());
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let mut sink = BufferSink::default();
+        let e = "Cargo.toml".read_changelog_with(&mut sink).unwrap_err();
+
+        assert!(matches!(e.cause(), Cause::Parse { line: 1, .. }));
+        assert_eq!(e.path(), Some(std::path::Path::new("Cargo.toml")));
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn read_changelog() {
+        assert_eq!(
+            "CHANGELOG.ron"
+                .read_changelog_silently()
+                .unwrap()
+                .sections()
+                .last()
+                .unwrap()
+                .changes()
+                .changes()[0]
+                .0,
+            "Added"
+        );
+    }
+}
+
+mod fragment {
+    use aeruginous_io::Fragment;
+
+    #[test]
+    fn merge() {
+        let mut first = Fragment::parse(
+            "(references: {\"b\": \"1\"}, changes: {\"Fixed\": [\"z\"], \
+             \"Added\": [\"y\"]})",
+        )
+        .unwrap();
+        let second = Fragment::parse(
+            "(references: {\"a\": \"2\", \"b\": \"1\"}, \
+             changes: {\"Added\": [\"x\"]})",
+        )
+        .unwrap();
+
+        assert!(first.merge(second).is_ok());
+
+        assert_eq!(
+            first.to_string(),
+            "(
+  references: {
+    \"a\": \"2\",
+    \"b\": \"1\",
+  },
+  changes: {
+    \"Added\": [
+      \"x\",
+      \"y\",
+    ],
+    \"Fixed\": [
+      \"z\",
+    ],
+  },
+)
+"
+        );
+    }
+
+    #[test]
+    fn merge_conflict() {
+        let mut first =
+            Fragment::parse("(references: {\"a\": \"1\"}, changes: {})")
+                .unwrap();
+        let second = Fragment::parse(
+            "(references: {\"a\": \"2\"}, changes: {\"Added\": [\"x\"]})",
+        )
+        .unwrap();
+        let e = first.merge(second).unwrap_err();
+
+        assert_eq!(e.kind(), Some(std::io::ErrorKind::InvalidData));
+        assert_eq!(
+            e.to_string(),
+            "Parsing the stream failed: conflicting URLs '1' and '2' for reference 'a'"
+        );
+        assert!(first.changes().is_empty());
+        assert_eq!(first.references(), [("a".to_string(), "1".to_string())]);
+    }
+
+    #[test]
+    fn escapes() {
+        let fragment =
+            Fragment::parse("// note\n(references: {}, changes: {\"A\": [\"\\\"\\u{41}\\\\\"]})")
+                .unwrap();
+
+        assert_eq!(fragment.changes()[0].1, ["\"A\\"]);
+        assert!(fragment.to_string().contains("\"\\\"A\\\\\""));
+    }
+}
+
+/******************************************************************************/
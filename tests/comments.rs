@@ -0,0 +1,180 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod comment_reader {
+    use aeruginous_io::{
+        BufferSink, CommentReader, Language, PathBufLikeTruncation,
+    };
+
+    fn prepare(content: &str, f: &str) {
+        assert!(content.truncate_silently(f).is_ok());
+    }
+
+    #[test]
+    fn c_blocks_do_not_nest() {
+        let f = "comment_reader_c_blocks_do_not_nest.txt";
+        prepare("/* a /* b */ int x; /* c */\n", f);
+
+        let blocks = f.comment_blocks_silently(Language::C).unwrap();
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].text(), "/* a /* b */");
+        assert_eq!(blocks[1].text(), "/* c */");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn code() {
+        let f = "comment_reader_code.txt";
+        prepare(
+            "// header\n\nfn main() { // call\n    /* a\n       b */\n    f(\"// no\");\n}\n",
+            f,
+        );
+
+        assert_eq!(
+            f.code_silently(Language::Rust).unwrap(),
+            "\nfn main() {\n    f(\"// no\");\n}\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn comments() {
+        let f = "comment_reader_comments.txt";
+        prepare("# a\n# b\nkey = 1 # c\n", f);
+
+        assert_eq!(
+            f.comments_silently(Language::Hash).unwrap(),
+            "# a\n# b\n# c\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn hash_needs_whitespace() {
+        let f = "comment_reader_hash_needs_whitespace.txt";
+        prepare("echo $# a#b 'x # y' # z\n", f);
+
+        assert_eq!(f.comments_silently(Language::Hash).unwrap(), "# z\n");
+        assert_eq!(
+            f.code_silently(Language::Hash).unwrap(),
+            "echo $# a#b 'x # y'\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn html() {
+        let f = "comment_reader_html.txt";
+        prepare("# Title\n<!-- a\nb -->\ntext <!-- c -->\n", f);
+
+        let blocks = f.comment_blocks_silently(Language::Html).unwrap();
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!((blocks[0].begin(), blocks[0].end()), (2, 3));
+        assert_eq!(blocks[0].text(), "<!-- a\nb -->");
+        assert_eq!((blocks[1].begin(), blocks[1].end()), (4, 4));
+        assert_eq!(f.code_silently(Language::Html).unwrap(), "# Title\ntext\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn language_from_path() {
+        use std::path::Path;
+
+        assert_eq!(
+            Language::from_path(Path::new("a.rs")),
+            Some(Language::Rust)
+        );
+        assert_eq!(Language::from_path(Path::new("a.h")), Some(Language::C));
+        assert_eq!(
+            Language::from_path(Path::new("a.yml")),
+            Some(Language::Hash)
+        );
+        assert_eq!(
+            Language::from_path(Path::new("a.md")),
+            Some(Language::Html)
+        );
+        assert_eq!(Language::from_path(Path::new("Makefile")), None);
+    }
+
+    #[test]
+    fn line_comments_merge() {
+        let f = "comment_reader_line_comments_merge.txt";
+        prepare("//! a\n//! b\n\n/// c\nfn f() {} // d\n// e\n", f);
+
+        let blocks = f.comment_blocks_silently(Language::Rust).unwrap();
+
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(blocks[0].text(), "//! a\n//! b");
+        assert_eq!((blocks[0].begin(), blocks[0].end()), (1, 2));
+        assert_eq!(blocks[1].text(), "/// c");
+        assert_eq!(blocks[2].text(), "// d");
+        assert_eq!(blocks[3].text(), "// e");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn missing_file() {
+        let mut sink = BufferSink::default();
+
+        assert!("comment_reader_missing_file.txt"
+            .comments_with(Language::Rust, &mut sink)
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn rust_blocks_nest() {
+        let f = "comment_reader_rust_blocks_nest.txt";
+        prepare("/* a /* b */ c */ x\n", f);
+
+        assert_eq!(
+            f.comments_silently(Language::Rust).unwrap(),
+            "/* a /* b */ c */\n"
+        );
+        assert_eq!(f.code_silently(Language::Rust).unwrap(), " x\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn rust_literals() {
+        let f = "comment_reader_rust_literals.txt";
+        prepare(
+            "let a = '\"'; // a\nlet b = r##\"/* \"# \"##; // b\nfn f<'a>(x: &'a str) {} // c\n",
+            f,
+        );
+
+        assert_eq!(
+            f.comments_silently(Language::Rust).unwrap(),
+            "// a\n// b\n// c\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+}
+
+/******************************************************************************/
@@ -0,0 +1,218 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod buffer_sink {
+    use aeruginous_io::{BufferSink, PathBufLikeReader, Writer};
+
+    #[test]
+    fn collect_messages() {
+        let mut sink = BufferSink::new();
+
+        assert!("no_such_file.txt".read_with(&mut sink).is_err());
+        assert!("no_such_file.txt".read_bytes_with(&mut sink).is_err());
+        assert!("test".write_with(Vec::new(), &mut sink).is_ok());
+        assert_eq!(sink.messages().len(), 2);
+        assert!(sink.messages()[0]
+            .starts_with("Opening 'no_such_file.txt' for reading failed: "));
+    }
+
+    #[test]
+    fn into_messages() {
+        let mut sink = BufferSink::new();
+
+        assert!("tests/assets/GPL-3.0.rs".read_with(&mut sink).is_ok());
+        assert!(sink.into_messages().is_empty());
+    }
+}
+
+mod logger {
+    use aeruginous_io::{
+        Format, Header, HeaderVerifier, Level, Logger, PathBufLikeReader,
+        PathBufLikeTruncation, Policy, Writer,
+    };
+
+    fn output(logger: Logger<Vec<u8>>) -> String {
+        String::from_utf8(logger.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn debug_records_success() {
+        let mut logger = Logger::new(Vec::new(), Level::Debug, Format::Plain);
+
+        assert!("test".write_with(Vec::new(), &mut logger).is_ok());
+        assert_eq!(output(logger), "Wrote 4 bytes to the stream.\n");
+    }
+
+    #[test]
+    fn error_ignores_success() {
+        let mut logger = Logger::new(Vec::new(), Level::Error, Format::Plain);
+
+        assert!("test".write_with(Vec::new(), &mut logger).is_ok());
+        assert!("no_such_file.txt".read_with(&mut logger).is_err());
+        assert!(output(logger)
+            .starts_with("Opening 'no_such_file.txt' for reading failed: "));
+    }
+
+    #[test]
+    fn gnu() {
+        let mut logger = Logger::new(
+            Vec::new(),
+            Level::Error,
+            Format::Gnu {
+                tool: "aeruginous".to_string(),
+            },
+        );
+
+        assert!(String::new().truncate_with("tests/", &mut logger).is_err());
+        assert!(output(logger).starts_with(
+            "aeruginous: tests/: opening for truncation failed: "
+        ));
+    }
+
+    #[test]
+    fn gnu_stream() {
+        let mut logger = Logger::new(
+            Vec::new(),
+            Level::Debug,
+            Format::Gnu {
+                tool: "aeruginous".to_string(),
+            },
+        );
+
+        assert!("test".write_with(Vec::new(), &mut logger).is_ok());
+        assert_eq!(output(logger), "aeruginous: wrote 4 bytes\n");
+    }
+
+    #[test]
+    fn info_ignores_reads() {
+        let mut logger = Logger::new(Vec::new(), Level::Info, Format::Plain);
+
+        assert!("tests/assets/line-endings.txt"
+            .read_with(&mut logger)
+            .is_ok());
+        assert!("test".write_with(Vec::new(), &mut logger).is_ok());
+        assert_eq!(output(logger), "Wrote 4 bytes to the stream.\n");
+    }
+
+    #[test]
+    fn json_lines() {
+        let mut logger =
+            Logger::new(Vec::new(), Level::Debug, Format::JsonLines);
+
+        assert!("tests/assets/line-endings.txt"
+            .read_with(&mut logger)
+            .is_ok());
+        assert!("test".write_with(Vec::new(), &mut logger).is_ok());
+        assert_eq!(
+            output(logger),
+            "{\"level\":\"debug\",\"operation\":\"read\",\
+             \"path\":\"tests/assets/line-endings.txt\",\
+             \"message\":\"read 24 bytes\"}\n\
+             {\"level\":\"info\",\"operation\":\"write\",\"path\":null,\
+             \"message\":\"wrote 4 bytes\"}\n"
+        );
+    }
+
+    #[test]
+    fn level_order() {
+        assert!(Level::Quiet < Level::Error);
+        assert!(Level::Error < Level::Warn);
+        assert!(Level::Warn < Level::Info);
+        assert!(Level::Info < Level::Debug);
+        assert_eq!(Level::default(), Level::Error);
+    }
+
+    #[test]
+    fn warn_records_skipped_files() {
+        let mut logger = Logger::new(Vec::new(), Level::Warn, Format::Plain);
+
+        assert!(vec!["tests/assets/line-endings.txt"]
+            .check_headers_with(
+                &Header::new("Header"),
+                Policy::Stop,
+                &mut logger
+            )
+            .is_ok());
+        assert_eq!(
+            output(logger),
+            "Warning for 'tests/assets/line-endings.txt': skipped due to an \
+             unknown comment syntax.\n"
+        );
+    }
+
+    #[test]
+    fn quiet() {
+        let mut logger = Logger::new(Vec::new(), Level::Quiet, Format::Plain);
+
+        assert!("no_such_file.txt".read_with(&mut logger).is_err());
+        assert!("test".write_with(Vec::new(), &mut logger).is_ok());
+        assert!(output(logger).is_empty());
+    }
+}
+
+mod null_sink {
+    use aeruginous_io::{NullSink, PathBufLikeReader};
+
+    #[test]
+    fn discard_messages() {
+        assert!("no_such_file.txt".read_with(NullSink).is_err());
+    }
+}
+
+mod sink {
+    use aeruginous_io::{Error, PathBufLikeReader, Sink};
+
+    struct Prefix {
+        messages: Vec<String>,
+        tool: &'static str,
+    }
+
+    impl Sink for Prefix {
+        fn report(&mut self, error: &Error) {
+            self.messages.push(format!("{}: {error}", self.tool));
+        }
+    }
+
+    #[test]
+    fn custom_implementation() {
+        let mut sink = Prefix {
+            messages: Vec::new(),
+            tool: "aeruginous",
+        };
+
+        assert!("no_such_file.txt".read_with(&mut sink).is_err());
+        assert!(sink.messages[0].starts_with("aeruginous: Opening "));
+    }
+}
+
+mod write_sink {
+    use aeruginous_io::{PathBufLikeTruncation, WriteSink};
+
+    #[test]
+    fn write_messages() {
+        let mut sink = WriteSink::new(Vec::new());
+
+        assert!(String::new().truncate_with("tests/", &mut sink).is_err());
+        assert!(String::from_utf8(sink.into_inner())
+            .unwrap()
+            .starts_with("Opening 'tests/' for truncation failed: "));
+    }
+}
+
+/******************************************************************************/
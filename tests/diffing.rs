@@ -0,0 +1,119 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod diff {
+    use aeruginous_io::Diff;
+    use std::path::Path;
+
+    const ORIGINAL: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+
+    #[test]
+    fn context() {
+        assert_eq!(
+            Diff::new().context(1).unified(
+                Path::new("f.txt"),
+                Some(ORIGINAL),
+                &ORIGINAL.replace("6\n", "six\n")
+            ),
+            "--- a/f.txt\n+++ b/f.txt\n@@ -5,3 +5,3 @@\n 5\n-6\n+six\n 7\n"
+        );
+    }
+
+    #[test]
+    fn creation() {
+        assert_eq!(
+            Diff::new().unified(Path::new("f.txt"), None, "a\nb\n"),
+            "--- /dev/null\n+++ b/f.txt\n@@ -0,0 +1,2 @@\n+a\n+b\n"
+        );
+    }
+
+    #[test]
+    fn deletion_without_context() {
+        assert_eq!(
+            Diff::new().context(0).unified(
+                Path::new("f.txt"),
+                Some("a\nb\nc\n"),
+                "a\nc\n"
+            ),
+            "--- a/f.txt\n+++ b/f.txt\n@@ -2 +1,0 @@\n-b\n"
+        );
+    }
+
+    #[test]
+    fn equal() {
+        assert!(Diff::new()
+            .unified(Path::new("f.txt"), Some(ORIGINAL), ORIGINAL)
+            .is_empty());
+    }
+
+    #[test]
+    fn large_rewrite() {
+        let original: String = (0..2000).map(|n| format!("{n}\n")).collect();
+        let modified: String =
+            (0..2000).rev().map(|n| format!("{n}\n")).collect();
+        let diff =
+            Diff::new().unified(Path::new("f.txt"), Some(&original), &modified);
+
+        let count = |marker| {
+            diff.lines()
+                .skip(2)
+                .filter(|l| l.starts_with(marker))
+                .count()
+        };
+
+        assert_eq!(count('-'), 1999);
+        assert_eq!(count('+'), 1999);
+        assert_eq!(count(' '), 1);
+    }
+
+    #[test]
+    fn missing_final_newline() {
+        assert_eq!(
+            Diff::new().unified(Path::new("f.txt"), Some("a\nb"), "a\nb\n"),
+            "--- a/f.txt\n+++ b/f.txt\n@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline \
+             at end of file\n+b\n"
+        );
+    }
+
+    #[test]
+    fn separate_hunks() {
+        assert_eq!(
+            Diff::new().unified(
+                Path::new("f.txt"),
+                Some(ORIGINAL),
+                &ORIGINAL
+                    .replacen("2\n", "two\n", 1)
+                    .replace("11\n", "eleven\n")
+            ),
+            "--- a/f.txt\n+++ b/f.txt\n\
+             @@ -1,5 +1,5 @@\n 1\n-2\n+two\n 3\n 4\n 5\n\
+             @@ -8,5 +8,5 @@\n 8\n 9\n 10\n-11\n+eleven\n 12\n"
+        );
+    }
+
+    #[test]
+    fn without_prefixes() {
+        assert!(Diff::new()
+            .prefixes(false)
+            .unified(Path::new("f.txt"), Some("a\n"), "b\n")
+            .starts_with("--- f.txt\n+++ f.txt\n"));
+    }
+}
+
+/******************************************************************************/
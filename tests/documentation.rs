@@ -0,0 +1,359 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod documentation_reader {
+    use aeruginous_io::{
+        DocComment, DocLocation, DocumentationReader, PathBufLikeReader,
+        PathBufLikeTruncation,
+    };
+
+    #[test]
+    fn asset_extraction() {
+        assert_eq!(
+            "tests/assets/GPL-3.0.rs"
+                .read_documentation_silently(
+                    DocComment::Outer,
+                    &DocLocation::First
+                )
+                .unwrap(),
+            "tests/assets/GPL-3.0.rs"
+                .read_silently()
+                .unwrap()
+                .replace("/// ", "")
+                .replace("///", "")
+        );
+    }
+
+    #[test]
+    fn missing_comment() {
+        assert!("tests/assets/GPL-3.0.rs"
+            .read_documentation_silently(DocComment::Inner, &DocLocation::First)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn mirrored_readme() {
+        assert_eq!(
+            "src/lib.rs"
+                .read_documentation_silently(
+                    DocComment::Inner,
+                    &DocLocation::First
+                )
+                .unwrap(),
+            "README.md".read_silently().unwrap()
+        );
+    }
+
+    #[test]
+    fn single_line_block() {
+        let f = "documentation_reader_single_line_block.rs";
+
+        assert!("/*! # Title */\nfn main() {}\n"
+            .truncate_silently(f)
+            .is_ok());
+        assert_eq!(
+            f.read_documentation_silently(
+                DocComment::Block,
+                &DocLocation::First
+            )
+            .unwrap(),
+            "# Title\n"
+        );
+        assert_eq!(
+            f.read_documentation_silently(
+                DocComment::Block,
+                &DocLocation::Line(2)
+            )
+            .unwrap(),
+            ""
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn unterminated_block() {
+        let f = "documentation_reader_unterminated_block.rs";
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!("/*!\n# Title\n".truncate_silently(f).is_ok());
+        assert!(f
+            .read_documentation_with(
+                DocComment::Block,
+                &DocLocation::First,
+                &mut sink
+            )
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+
+        std::fs::remove_file(f).unwrap();
+    }
+}
+
+mod documentation_truncation {
+    use aeruginous_io::{
+        Check, DocComment, DocLocation, DocumentationReader,
+        DocumentationTruncation, Markers, PathBufLikeReader,
+        PathBufLikeTruncation,
+    };
+
+    #[test]
+    fn block() {
+        let f = "documentation_truncation_block.rs";
+
+        assert!("fn main() {}\n".truncate_silently(f).is_ok());
+        assert!("# Title\n\nText\n"
+            .truncate_documentation_silently(
+                f,
+                DocComment::Block,
+                &DocLocation::First
+            )
+            .is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "/*!\n# Title\n\nText\n*/\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            f.read_documentation_silently(
+                DocComment::Block,
+                &DocLocation::First
+            )
+            .unwrap(),
+            "# Title\n\nText\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn drift() {
+        let readme = "README.md".read_silently().unwrap();
+
+        assert_eq!(
+            readme
+                .as_str()
+                .check_documentation_silently(
+                    "src/lib.rs",
+                    DocComment::Inner,
+                    &DocLocation::First
+                )
+                .unwrap(),
+            Check::Unchanged
+        );
+        assert_eq!(
+            (readme + "More.\n")
+                .check_documentation_loudly(
+                    "src/lib.rs",
+                    DocComment::Inner,
+                    &DocLocation::First
+                )
+                .unwrap(),
+            Check::WouldModify
+        );
+        assert_eq!(
+            "# Title\n"
+                .check_documentation_silently(
+                    "tests/assets/GPL-3.0.rs",
+                    DocComment::Inner,
+                    &DocLocation::First
+                )
+                .unwrap(),
+            Check::WouldCreate
+        );
+    }
+
+    #[test]
+    fn insertion_after_license_header() {
+        let f = "documentation_truncation_insertion_after_license_header.rs";
+
+        assert!("/****\\\n| GPL |\n\\****/\n\n#![deny(missing_docs)]\n"
+            .truncate_silently(f)
+            .is_ok());
+        assert!("# Title\n\nText\n"
+            .truncate_documentation_loudly(
+                f,
+                DocComment::Inner,
+                &DocLocation::First
+            )
+            .is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "/****\\\n| GPL |\n\\****/\n\n//! # Title\n//!\n//! Text\n\n\
+             #![deny(missing_docs)]\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn item() {
+        let f = "documentation_truncation_item.rs";
+
+        assert!("/// A.\nfn a() {}\n\n#[test]\nfn b() {}\n"
+            .truncate_silently(f)
+            .is_ok());
+        assert!("B.\n"
+            .truncate_documentation_silently(
+                f,
+                DocComment::Outer,
+                &DocLocation::Item("b".to_string())
+            )
+            .is_ok());
+        assert!("New.\n"
+            .truncate_documentation_silently(
+                f,
+                DocComment::Outer,
+                &DocLocation::Item("a".to_string())
+            )
+            .is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "/// New.\nfn a() {}\n\n/// B.\n#[test]\nfn b() {}\n"
+        );
+        assert_eq!(
+            "B.\n"
+                .check_documentation_silently(
+                    f,
+                    DocComment::Outer,
+                    &DocLocation::Item("b".to_string())
+                )
+                .unwrap(),
+            Check::Unchanged
+        );
+
+        let e = "C.\n"
+            .truncate_documentation_silently(
+                f,
+                DocComment::Outer,
+                &DocLocation::Item("c".to_string()),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            format!(
+                "Parsing '{f}' failed in line 7, column 1: there is no item \
+                 named 'c'."
+            )
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn line() {
+        let f = "documentation_truncation_line.rs";
+
+        assert!("mod m {\n    fn f() {}\n}\n".truncate_silently(f).is_ok());
+        assert!("Text.\n"
+            .truncate_documentation_silently(
+                f,
+                DocComment::Outer,
+                &DocLocation::Line(2)
+            )
+            .is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "mod m {\n    /// Text.\n    fn f() {}\n}\n"
+        );
+        assert_eq!(
+            f.read_documentation_silently(
+                DocComment::Outer,
+                &DocLocation::Line(2)
+            )
+            .unwrap(),
+            "Text.\n"
+        );
+        assert!("Text.\n"
+            .truncate_documentation_silently(
+                f,
+                DocComment::Outer,
+                &DocLocation::Line(6)
+            )
+            .is_err());
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn region() {
+        let f = "documentation_truncation_region.rs";
+        let location = DocLocation::Region("docs".to_string(), Markers::new());
+
+        assert!("/// Other.\n// #[aeruginous::mercy::docs::start]\n\
+                 // #[aeruginous::mercy::docs::end]\nfn f() {}\n"
+            .truncate_silently(f)
+            .is_ok());
+        assert!("Text.\n"
+            .truncate_documentation_silently(f, DocComment::Outer, &location)
+            .is_ok());
+        assert!("New.\n"
+            .truncate_documentation_silently(f, DocComment::Outer, &location)
+            .is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "/// Other.\n// #[aeruginous::mercy::docs::start]\n/// New.\n\
+             // #[aeruginous::mercy::docs::end]\nfn f() {}\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn replace_indented() {
+        let f = "documentation_truncation_replace_indented.rs";
+
+        assert!(
+            "mod m {\n    /// Old.\n    ///\n    /// Text.\n    fn f() {}\n}\n"
+                .truncate_silently(f)
+                .is_ok()
+        );
+        assert!("New.\n"
+            .truncate_documentation_silently(
+                f,
+                DocComment::Outer,
+                &DocLocation::First
+            )
+            .is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "mod m {\n    /// New.\n    fn f() {}\n}\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!("# Title\n"
+            .truncate_documentation_with(
+                "no_such_file.rs",
+                DocComment::Inner,
+                &DocLocation::First,
+                &mut sink
+            )
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+}
+
+/******************************************************************************/
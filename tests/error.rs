@@ -0,0 +1,298 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod error {
+    use aeruginous_io::{
+        Cause, Error, Operation, PathBufLikeReader, PathBufLikeTruncation,
+        Step, Writer,
+    };
+
+    struct Short;
+
+    impl std::io::Write for Short {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            Ok(buf.len() / 2)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn read_with_sysexits() -> sysexits::Result<String> {
+        Ok("no_such_file.txt".read_silently()?)
+    }
+
+    #[test]
+    fn context_open() {
+        let e = "no_such_file.txt".read_silently().unwrap_err();
+
+        assert_eq!(e.operation(), Operation::Read);
+        assert_eq!(e.step(), Step::Open);
+        assert_eq!(e.path().unwrap().to_str().unwrap(), "no_such_file.txt");
+        assert_eq!(e.kind(), Some(std::io::ErrorKind::NotFound));
+        assert!(e
+            .to_string()
+            .starts_with("Opening 'no_such_file.txt' for reading failed: "));
+    }
+
+    #[test]
+    fn context_partial_write() {
+        let e = "test".write_silently(Short).unwrap_err();
+
+        assert_eq!(e.operation(), Operation::Write);
+        assert_eq!(e.step(), Step::Write);
+        assert!(e.path().is_none());
+        assert!(e.kind().is_none());
+        assert!(matches!(
+            e.cause(),
+            Cause::PartialWrite {
+                expected: Some(4),
+                written: 3
+            }
+        ));
+        assert_eq!(
+            e.to_string(),
+            "Creating an exact copy in the stream was not possible: only 3 of \
+             4 bytes were written."
+        );
+    }
+
+    #[test]
+    fn context_remove() {
+        let e = Error::new(
+            Operation::Write,
+            Step::Remove,
+            Some("fragment.ron".into()),
+            Cause::Io(std::io::ErrorKind::NotFound.into()),
+        );
+
+        assert_eq!(e.message(), "removing failed: entity not found");
+        assert_eq!(
+            e.to_string(),
+            "Removing 'fragment.ron' failed: entity not found"
+        );
+    }
+
+    #[test]
+    fn context_synchronise() {
+        let e = Error::new(
+            Operation::Truncate,
+            Step::Synchronise,
+            Some("file.txt".into()),
+            Cause::Io(std::io::ErrorKind::PermissionDenied.into()),
+        );
+
+        assert_eq!(
+            e.message(),
+            "synchronising the directory failed: permission denied"
+        );
+        assert_eq!(
+            e.to_string(),
+            "Synchronising the directory of 'file.txt' failed: permission \
+             denied"
+        );
+    }
+
+    #[test]
+    fn context_truncation() {
+        let e = String::new().truncate_silently("tests/").unwrap_err();
+
+        assert_eq!(e.operation(), Operation::Truncate);
+        assert_eq!(e.step(), Step::Open);
+        assert_eq!(e.path().unwrap().to_str().unwrap(), "tests/");
+    }
+
+    #[test]
+    fn source() {
+        let e = "no_such_file.txt".read_silently().unwrap_err();
+
+        assert!(std::error::Error::source(&e).is_some());
+    }
+
+    #[test]
+    fn sysexits_compatibility() {
+        assert_eq!(
+            read_with_sysexits().unwrap_err(),
+            sysexits::ExitCode::NoInput
+        );
+    }
+
+    #[test]
+    fn sysexits_conversion_partial_write() {
+        assert_eq!(
+            sysexits::ExitCode::from(Error::new(
+                Operation::Write,
+                Step::Write,
+                None,
+                Cause::PartialWrite {
+                    expected: Some(2),
+                    written: 1
+                }
+            )),
+            sysexits::ExitCode::IoErr
+        );
+    }
+}
+
+mod exit_code {
+    use aeruginous_io::{
+        Cause, Check, Error, Operation, PathBufLikeAppendix, PathBufLikeReader,
+        PathBufLikeTruncation, Step, VectorReader, Writer,
+    };
+    use sysexits::ExitCode;
+
+    struct Broken;
+
+    impl std::io::Write for Broken {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(Check::Unchanged.exit_code(), ExitCode::Ok);
+        assert_eq!(Check::WouldCreate.exit_code(), ExitCode::DataErr);
+        assert_eq!(ExitCode::from(Check::WouldModify), ExitCode::DataErr);
+    }
+
+    #[test]
+    fn path_buf_like_appendix_append_silently() {
+        assert_eq!(
+            String::new()
+                .append_silently("tests/")
+                .unwrap_err()
+                .exit_code(),
+            ExitCode::CantCreat
+        );
+    }
+
+    #[test]
+    fn path_buf_like_appendix_append_silently_missing_directory() {
+        assert_eq!(
+            String::new()
+                .append_silently("no_such_directory/file.txt")
+                .unwrap_err()
+                .exit_code(),
+            ExitCode::CantCreat
+        );
+    }
+
+    #[test]
+    fn path_buf_like_reader_read_bytes_silently() {
+        assert_eq!(
+            "no_such_file.txt"
+                .read_bytes_silently()
+                .unwrap_err()
+                .exit_code(),
+            ExitCode::NoInput
+        );
+    }
+
+    #[test]
+    fn path_buf_like_reader_read_silently_directory() {
+        assert_eq!(
+            "tests/".read_silently().unwrap_err().exit_code(),
+            ExitCode::NoInput
+        );
+    }
+
+    #[test]
+    fn path_buf_like_reader_read_silently_invalid_utf_8() {
+        assert_eq!(
+            "tests/assets/non-utf-8.bin"
+                .read_silently()
+                .unwrap_err()
+                .exit_code(),
+            ExitCode::DataErr
+        );
+    }
+
+    #[test]
+    fn path_buf_like_truncation_truncate_silently() {
+        assert_eq!(
+            String::new()
+                .truncate_silently("tests/")
+                .unwrap_err()
+                .exit_code(),
+            ExitCode::CantCreat
+        );
+    }
+
+    #[test]
+    fn permission_denied() {
+        for operation in [
+            Operation::Append,
+            Operation::Read,
+            Operation::Truncate,
+            Operation::Write,
+        ] {
+            assert_eq!(
+                Error::new(
+                    operation,
+                    Step::Open,
+                    None,
+                    Cause::Io(std::io::ErrorKind::PermissionDenied.into())
+                )
+                .exit_code(),
+                ExitCode::NoPerm
+            );
+        }
+    }
+
+    #[test]
+    fn vector_reader_read_silently() {
+        assert_eq!(
+            vec!["tests/assets/GPL-3.0.rs", "no_such_file.txt"]
+                .read_silently(&b""[..])
+                .unwrap_err()
+                .exit_code(),
+            ExitCode::NoInput
+        );
+    }
+
+    #[test]
+    fn writer_write_silently() {
+        assert_eq!(
+            "test".write_silently(Broken).unwrap_err().exit_code(),
+            ExitCode::IoErr
+        );
+    }
+
+    #[test]
+    fn writing_after_opening() {
+        assert_eq!(
+            Error::new(
+                Operation::Truncate,
+                Step::Write,
+                None,
+                Cause::Io(std::io::ErrorKind::StorageFull.into())
+            )
+            .exit_code(),
+            ExitCode::IoErr
+        );
+    }
+}
+
+/******************************************************************************/
@@ -0,0 +1,173 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod fragment_directory {
+    use aeruginous_io::{
+        ChangelogReader, Format, Fragment, FragmentDirectory, Level, Logger,
+        PathBufLikeReader, Version,
+    };
+    use std::path::PathBuf;
+
+    fn fragment(category: &str, change: &str) -> Fragment {
+        let mut fragment = Fragment::new();
+        fragment.add(category, change);
+        fragment
+    }
+
+    #[test]
+    fn read_fragments() {
+        let directory = "fragment_directory_read_fragments";
+        let first = directory
+            .write_fragment_silently(&fragment("Fixed", "b"), "first")
+            .unwrap();
+        let second = directory
+            .write_fragment_silently(&fragment("Added", "c"), "second")
+            .unwrap();
+        let third = directory
+            .write_fragment_silently(&fragment("Fixed", "a"), "third")
+            .unwrap();
+
+        std::fs::write(PathBuf::from(directory).join("README.md"), "").unwrap();
+
+        let (merged, mut files) = directory.read_fragments_silently().unwrap();
+        let mut expected = vec![first, second, third];
+
+        files.sort();
+        expected.sort();
+
+        assert_eq!(files, expected);
+        assert_eq!(
+            merged.changes(),
+            [
+                ("Added".to_string(), vec!["c".to_string()]),
+                ("Fixed".to_string(), vec!["a".to_string(), "b".to_string()]),
+            ]
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn release_fragments() {
+        let root = "fragment_directory_release_fragments";
+        let directory = PathBuf::from(root).join("changelog.d");
+        let changelog = PathBuf::from(root).join("CHANGELOG.ron");
+
+        directory
+            .write_fragment_silently(&fragment("Added", "x"), "x")
+            .unwrap();
+        std::fs::copy("CHANGELOG.ron", &changelog).unwrap();
+
+        let section = directory
+            .release_fragments_silently(
+                changelog.clone(),
+                Version::new(0, 9, 0),
+            )
+            .unwrap();
+        let released = changelog.read_changelog_silently().unwrap();
+
+        assert_eq!(released.sections()[0], section);
+        assert_eq!(section.version(), Version::new(0, 9, 0));
+        assert_eq!(section.released().len(), 30);
+        assert!(section.released().ends_with('Z'));
+        assert_eq!(section.changes().changes()[0].1, ["x"]);
+        assert_eq!(
+            released.sections()[1..],
+            *"CHANGELOG.ron"
+                .read_changelog_silently()
+                .unwrap()
+                .sections()
+        );
+        assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 0);
+
+        let file = directory
+            .write_fragment_silently(&fragment("Fixed", "y"), "y")
+            .unwrap();
+        let mut logger = Logger::new(Vec::new(), Level::Info, Format::Plain);
+
+        assert!(directory
+            .release_fragments_with(
+                changelog.clone(),
+                Version::new(0, 9, 1),
+                &mut logger
+            )
+            .is_ok());
+        assert!(String::from_utf8(logger.into_inner())
+            .unwrap()
+            .ends_with(&format!("Removed '{}'.\n", file.display())));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn release_fragments_keeps_fragments_on_error() {
+        let directory = "fragment_directory_release_fragments_keeps_fragments";
+        let file = directory
+            .write_fragment_silently(&fragment("Added", "x"), "x")
+            .unwrap();
+
+        assert!(directory
+            .release_fragments_silently(
+                PathBuf::from(directory).join("CHANGELOG.ron"),
+                Version::new(1, 0, 0)
+            )
+            .is_err());
+        assert!(file.read_fragment_silently().is_ok());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn write_fragment() {
+        let directory = "fragment_directory_write_fragment";
+        let fragment = fragment("Added", "FragmentDirectory");
+        let first = directory.write_fragment_silently(&fragment, "x").unwrap();
+        let second = directory.write_fragment_silently(&fragment, "x").unwrap();
+        let name = first.file_name().unwrap().to_str().unwrap().to_string();
+
+        assert_ne!(first, second);
+        assert_eq!(name.len(), "20240606_114935_x.ron".len());
+        assert!(name[..8].bytes().all(|b| b.is_ascii_digit()));
+        assert!(name.ends_with("_x.ron"));
+        assert_eq!(first.read_silently().unwrap(), fragment.to_string());
+        assert_eq!(second.read_fragment_silently().unwrap(), fragment);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn write_fragment_invalid_slug() {
+        let directory = "fragment_directory_write_fragment_invalid_slug";
+        let fragment = fragment("Added", "x");
+
+        for slug in ["../x", "x/y", ".."] {
+            assert_eq!(
+                directory
+                    .write_fragment_silently(&fragment, slug)
+                    .unwrap_err()
+                    .kind(),
+                Some(std::io::ErrorKind::InvalidInput)
+            );
+        }
+
+        assert!(!std::path::Path::new(directory).exists());
+    }
+}
+
+/******************************************************************************/
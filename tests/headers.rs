@@ -0,0 +1,316 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod header {
+    use aeruginous_io::{BannerStyle, Header, HeaderStyle, Language};
+
+    #[test]
+    fn render() {
+        let header = Header::new("Copyright (C) 2024\n\nGPL\n");
+
+        assert_eq!(
+            header.render(Language::Rust),
+            "// Copyright (C) 2024\n//\n// GPL"
+        );
+        assert_eq!(
+            header.render(Language::Hash),
+            "# Copyright (C) 2024\n#\n# GPL"
+        );
+        assert_eq!(
+            header.render(Language::Html),
+            "<!--\nCopyright (C) 2024\n\nGPL\n-->"
+        );
+    }
+
+    #[test]
+    fn render_styles() {
+        let header = Header::new("GPL");
+
+        assert_eq!(
+            header
+                .clone()
+                .style(HeaderStyle::Documentation)
+                .render(Language::Rust),
+            "/// GPL"
+        );
+        assert_eq!(
+            header
+                .clone()
+                .style(HeaderStyle::Documentation)
+                .render(Language::Hash),
+            "# GPL"
+        );
+        assert_eq!(
+            header
+                .style(HeaderStyle::Boxed {
+                    style: BannerStyle::new().width(12),
+                    title: None,
+                })
+                .render(Language::Hash),
+            "# /********\\\n# |        |\n# |  GPL   |\n# |        |\n\
+             # \\********/"
+        );
+    }
+}
+
+mod header_status {
+    use aeruginous_io::HeaderStatus;
+    use sysexits::ExitCode;
+
+    #[test]
+    fn exit_code() {
+        assert_eq!(HeaderStatus::Current.exit_code(), ExitCode::Ok);
+        assert_eq!(HeaderStatus::Failed.exit_code(), ExitCode::IoErr);
+        assert_eq!(HeaderStatus::Inserted.exit_code(), ExitCode::Ok);
+        assert_eq!(HeaderStatus::Replaced.exit_code(), ExitCode::Ok);
+        assert_eq!(HeaderStatus::Missing.exit_code(), ExitCode::DataErr);
+        assert_eq!(HeaderStatus::Outdated.exit_code(), ExitCode::DataErr);
+        assert_eq!(
+            ExitCode::from(HeaderStatus::Unsupported),
+            ExitCode::DataErr
+        );
+    }
+}
+
+mod header_verifier {
+    use aeruginous_io::{
+        BannerStyle, BufferSink, Header, HeaderStatus, HeaderStyle,
+        HeaderVerifier, Language, PathBufLikeReader, PathBufLikeTruncation,
+        Policy,
+    };
+    use std::path::PathBuf;
+
+    fn header() -> Header {
+        Header::new(
+            &"tests/assets/GPL-3.0.rs"
+                .read_silently()
+                .unwrap()
+                .replace("/// ", "")
+                .replace("///", ""),
+        )
+    }
+
+    #[test]
+    fn boxed_style() {
+        let header = header().style(HeaderStyle::Boxed {
+            style: BannerStyle::new(),
+            title: Some("GNU General Public License 3.0".to_string()),
+        });
+        let files = std::fs::read_dir("src")
+            .unwrap()
+            .chain(std::fs::read_dir("tests").unwrap())
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|e| e == "rs"))
+            .collect::<Vec<_>>();
+
+        assert!(files.len() > 20);
+        assert!(files
+            .check_headers_silently(&header, Policy::Stop)
+            .unwrap()
+            .into_iter()
+            .all(|(_, status)| status == HeaderStatus::Current));
+    }
+
+    #[test]
+    fn boxed_style_replace() {
+        let f = "header_verifier_boxed_style_replace.rs";
+        let header = header().style(HeaderStyle::Boxed {
+            style: BannerStyle::new(),
+            title: Some("GNU General Public License 3.0".to_string()),
+        });
+        let original = "src/reading.rs".read_silently().unwrap();
+
+        assert!(original
+            .replacen("2024", "2023", 1)
+            .truncate_silently(f)
+            .is_ok());
+        assert_eq!(
+            vec![f]
+                .insert_headers_silently(&header, Policy::Stop)
+                .unwrap(),
+            vec![(PathBuf::from(f), HeaderStatus::Replaced)]
+        );
+        assert_eq!(f.read_silently().unwrap(), original);
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn documentation_style() {
+        assert_eq!(
+            vec!["tests/assets/GPL-3.0.rs"]
+                .check_headers_silently(
+                    &header().style(HeaderStyle::Documentation),
+                    Policy::Stop
+                )
+                .unwrap(),
+            vec![(
+                PathBuf::from("tests/assets/GPL-3.0.rs"),
+                HeaderStatus::Current
+            )]
+        );
+    }
+
+    #[test]
+    fn check() {
+        let current = "header_verifier_check_current.rs";
+        let missing = "header_verifier_check_missing.sh";
+        let outdated = "header_verifier_check_outdated.rs";
+        let unsupported = "header_verifier_check_unsupported.txt";
+        let header = header();
+        let rendered = header.render(Language::Rust) + "\n\nfn main() {}\n";
+
+        assert!(rendered.as_str().truncate_silently(current).is_ok());
+        assert!("#!/bin/sh\necho\n".truncate_silently(missing).is_ok());
+        assert!(rendered
+            .replace("2024", "2023")
+            .truncate_silently(outdated)
+            .is_ok());
+        assert!("text\n".truncate_silently(unsupported).is_ok());
+
+        let files = vec![current, missing, outdated, unsupported];
+
+        assert_eq!(
+            files.check_headers_silently(&header, Policy::Stop).unwrap(),
+            vec![
+                (PathBuf::from(current), HeaderStatus::Current),
+                (PathBuf::from(missing), HeaderStatus::Missing),
+                (PathBuf::from(outdated), HeaderStatus::Outdated),
+                (PathBuf::from(unsupported), HeaderStatus::Unsupported),
+            ]
+        );
+        assert_eq!(
+            outdated.read_silently().unwrap(),
+            rendered.replace("2024", "2023")
+        );
+
+        for file in files {
+            std::fs::remove_file(file).unwrap();
+        }
+    }
+
+    #[test]
+    fn insert() {
+        let current = "header_verifier_insert_current.rs";
+        let missing = "header_verifier_insert_missing.sh";
+        let outdated = "header_verifier_insert_outdated.rs";
+        let header = Header::new("Copyright (C) 2024 Kevin Matthes\n\nGPL");
+        let code = "\n//! Documentation.\nfn main() {}\n";
+
+        assert!("// Copyright (C) 2024 Kevin Matthes\n//\n// GPL\n"
+            .truncate_silently(current)
+            .is_ok());
+        assert!("#!/bin/sh\n# Comment.\necho\n"
+            .truncate_silently(missing)
+            .is_ok());
+        assert!(("// Copyright (C) 2023 Kevin Matthes\n// GPL\n".to_string()
+            + code)
+            .truncate_silently(outdated)
+            .is_ok());
+
+        assert_eq!(
+            vec![current, missing, outdated]
+                .insert_headers_silently(&header, Policy::Stop)
+                .unwrap(),
+            vec![
+                (PathBuf::from(current), HeaderStatus::Current),
+                (PathBuf::from(missing), HeaderStatus::Inserted),
+                (PathBuf::from(outdated), HeaderStatus::Replaced),
+            ]
+        );
+        assert_eq!(
+            missing.read_silently().unwrap(),
+            "#!/bin/sh\n# Copyright (C) 2024 Kevin Matthes\n#\n# GPL\n\n\
+             # Comment.\necho\n"
+        );
+        assert_eq!(
+            outdated.read_silently().unwrap(),
+            "// Copyright (C) 2024 Kevin Matthes\n//\n// GPL\n".to_string()
+                + code
+        );
+        assert_eq!(
+            vec![current, missing, outdated]
+                .check_headers_silently(&header, Policy::Stop)
+                .unwrap()
+                .into_iter()
+                .map(|(_, status)| status)
+                .collect::<Vec<_>>(),
+            vec![HeaderStatus::Current; 3]
+        );
+
+        std::fs::remove_file(current).unwrap();
+        std::fs::remove_file(missing).unwrap();
+        std::fs::remove_file(outdated).unwrap();
+    }
+
+    #[test]
+    fn language() {
+        let f = "header_verifier_language.txt";
+
+        assert!("text\n".truncate_silently(f).is_ok());
+        assert_eq!(
+            vec![f]
+                .insert_headers_silently(
+                    &Header::new("License").language(Language::Html),
+                    Policy::Stop
+                )
+                .unwrap(),
+            vec![(PathBuf::from(f), HeaderStatus::Inserted)]
+        );
+        assert_eq!(f.read_silently().unwrap(), "<!--\nLicense\n-->\n\ntext\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn missing_file() {
+        assert!(vec!["header_verifier_missing_file.rs"]
+            .check_headers_silently(&Header::new("License"), Policy::Stop)
+            .is_err());
+    }
+
+    #[test]
+    fn missing_file_collect() {
+        let f = "header_verifier_missing_file_collect.rs";
+        let missing = "header_verifier_missing_file_collect_missing.rs";
+        let mut sink = BufferSink::new();
+
+        assert!("fn main() {}\n".truncate_silently(f).is_ok());
+        assert_eq!(
+            vec![missing, f]
+                .insert_headers_with(
+                    &Header::new("License"),
+                    Policy::Collect,
+                    &mut sink
+                )
+                .unwrap(),
+            vec![
+                (PathBuf::from(missing), HeaderStatus::Failed),
+                (PathBuf::from(f), HeaderStatus::Inserted),
+            ]
+        );
+        assert_eq!(f.read_silently().unwrap(), "// License\n\nfn main() {}\n");
+        assert_eq!(sink.messages().len(), 1);
+        assert!(!std::path::Path::new(missing).exists());
+
+        std::fs::remove_file(f).unwrap();
+    }
+}
+
+/******************************************************************************/
@@ -0,0 +1,239 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod patcher {
+    use aeruginous_io::{
+        Diff, Format, Level, Logger, Patch, Patcher, PathBufLikeReader,
+        PathBufLikeTruncation,
+    };
+
+    const ORIGINAL: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+
+    #[test]
+    fn all_or_nothing() {
+        let f = "patcher_all_or_nothing.txt";
+        let g = "patcher_all_or_nothing_other.txt";
+
+        assert!(ORIGINAL.truncate_silently(f).is_ok());
+        assert!("a\n".truncate_silently(g).is_ok());
+
+        let diff = format!(
+            "--- a/{f}\n+++ b/{f}\n@@ -1 +1 @@\n-1\n+one\n\
+             --- a/{g}\n+++ b/{g}\n@@ -1 +1 @@\n-x\n+y\n"
+        );
+        let rejections = diff
+            .as_str()
+            .patch_silently(Patch::new().all_or_nothing(true))
+            .unwrap();
+
+        assert_eq!(rejections.len(), 1);
+        assert_eq!(f.read_silently().unwrap(), ORIGINAL);
+
+        let rejections = diff.patch_silently(Patch::new()).unwrap();
+
+        assert_eq!(rejections.len(), 1);
+        assert_eq!(rejections[0].hunk(), 1);
+        assert_eq!(rejections[0].path(), std::path::Path::new(g));
+        assert_eq!(rejections[0].text(), "@@ -1 +1 @@\n-x\n+y\n");
+        assert_eq!(
+            f.read_silently().unwrap(),
+            ORIGINAL.replacen('1', "one", 1)
+        );
+        assert_eq!(g.read_silently().unwrap(), "a\n");
+
+        std::fs::remove_file(f).unwrap();
+        std::fs::remove_file(g).unwrap();
+    }
+
+    #[test]
+    fn creation_and_removal() {
+        let f = "patcher_creation_and_removal.txt";
+
+        assert!(format!("--- /dev/null\n+++ b/{f}\n@@ -0,0 +1 @@\n+test\n")
+            .patch_silently(Patch::new())
+            .unwrap()
+            .is_empty());
+        assert_eq!(f.read_silently().unwrap(), "test\n");
+        assert!(format!("--- a/{f}\n+++ /dev/null\n@@ -1 +0,0 @@\n-test\n")
+            .patch_silently(Patch::new())
+            .unwrap()
+            .is_empty());
+        assert!(!std::path::Path::new(f).exists());
+    }
+
+    #[test]
+    fn creation_in_new_directory() {
+        let d = "patcher_creation_in_new_directory";
+        let f = format!("{d}/nested/file.txt");
+        let g = "patcher_creation_in_new_directory.txt";
+        let mut logger = Logger::new(Vec::new(), Level::Info, Format::Plain);
+
+        assert!("test\n".truncate_silently(g).is_ok());
+        assert!(format!(
+            "--- /dev/null\n+++ b/{f}\n@@ -0,0 +1 @@\n+test\n\
+             --- a/{g}\n+++ /dev/null\n@@ -1 +0,0 @@\n-test\n"
+        )
+        .patch_with(Patch::new(), &mut logger)
+        .unwrap()
+        .is_empty());
+        assert_eq!(f.read_silently().unwrap(), "test\n");
+        assert!(!std::path::Path::new(g).exists());
+        assert!(String::from_utf8(logger.into_inner())
+            .unwrap()
+            .starts_with(&format!("Removed '{g}'.\n")));
+
+        std::fs::remove_dir_all(d).unwrap();
+    }
+
+    #[test]
+    fn fuzz() {
+        let f = "patcher_fuzz.txt";
+        let diff = format!(
+            "--- a/{f}\n+++ b/{f}\n@@ -4,7 +4,7 @@\n four\n 5\n 6\n-7\n+seven\n \
+             8\n 9\n 10\n"
+        );
+
+        assert!(ORIGINAL.truncate_silently(f).is_ok());
+        assert_eq!(
+            diff.as_str().patch_silently(Patch::new()).unwrap().len(),
+            1
+        );
+        assert_eq!(f.read_silently().unwrap(), ORIGINAL);
+        assert!(diff
+            .patch_silently(Patch::new().fuzz(1))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            ORIGINAL.replace("7\n", "seven\n")
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn malformed() {
+        let e = "--- a/f.txt\n+++ b/f.txt\n@@ -1 +1 @@\n-a\n"
+            .patch_silently(Patch::new())
+            .unwrap_err();
+
+        assert_eq!(e.exit_code(), sysexits::ExitCode::DataErr);
+        assert_eq!(
+            e.to_string(),
+            "Parsing the stream failed in line 5, column 1: unexpected end of \
+             hunk."
+        );
+    }
+
+    #[test]
+    fn malformed_start() {
+        let e = "--- a/f.txt\n+++ b/f.txt\n@@ -0,1 +0,1 @@\n-a\n+b\n"
+            .patch_silently(Patch::new())
+            .unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "Parsing the stream failed in line 3, column 1: malformed hunk \
+             header."
+        );
+    }
+
+    #[test]
+    fn staged_writes() {
+        let f = "patcher_staged_writes.txt";
+
+        assert!(ORIGINAL.truncate_silently(f).is_ok());
+        assert!(format!(
+            "--- a/{f}\n+++ b/{f}\n@@ -1 +1 @@\n-1\n+one\n\
+             --- /dev/null\n+++ b/tests\n@@ -0,0 +1 @@\n+test\n"
+        )
+        .patch_silently(Patch::new())
+        .is_err());
+        assert_eq!(f.read_silently().unwrap(), ORIGINAL);
+        assert!(std::fs::read_dir(".").unwrap().all(|e| !e
+            .unwrap()
+            .file_name()
+            .to_string_lossy()
+            .starts_with(&format!(".{f}."))));
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn missing_file() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!("--- a/no_such_file.txt\n+++ b/no_such_file.txt\n\
+                 @@ -1 +1 @@\n-a\n+b\n"
+            .patch_with(Patch::new(), &mut sink)
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn non_utf_8() {
+        let e = aeruginous_io::Bytes(b"--- a/f.txt\n\xff\n")
+            .patch_silently(Patch::new())
+            .unwrap_err();
+
+        assert_eq!(e.kind(), Some(std::io::ErrorKind::InvalidData));
+        assert!(e.path().is_none());
+    }
+
+    #[test]
+    fn offset() {
+        let f = "patcher_offset.txt";
+
+        assert!(ORIGINAL.truncate_silently(f).is_ok());
+
+        let diff = ORIGINAL
+            .replace("3\n", "three\n")
+            .replace("10\n", "ten\n")
+            .truncate_dry_run_silently(f, Diff::new().context(1))
+            .unwrap();
+
+        assert!(format!("0\n{ORIGINAL}").truncate_silently(f).is_ok());
+        assert!(diff.patch_loudly(Patch::new()).unwrap().is_empty());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            format!("0\n{ORIGINAL}")
+                .replace("3\n", "three\n")
+                .replace("10\n", "ten\n")
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn round_trip() {
+        let f = "patcher_round_trip.txt";
+        let modified = "1\n2\nthree\n4\n5\n6\n7\n8\n9\n11\n12\n13";
+
+        assert!(ORIGINAL.truncate_silently(f).is_ok());
+
+        let diff = modified.truncate_dry_run_silently(f, Diff::new()).unwrap();
+
+        assert!(diff.patch_silently(Patch::new()).unwrap().is_empty());
+        assert_eq!(f.read_silently().unwrap(), modified);
+
+        std::fs::remove_file(f).unwrap();
+    }
+}
+
+/******************************************************************************/
@@ -18,7 +18,99 @@
 \******************************************************************************/
 
 mod buf_read_reader {
-    use aeruginous_io::BufReadReader;
+    use aeruginous_io::{BufReadReader, LineEnding, PathBufLikeReader};
+
+    #[test]
+    fn read_with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!({ &(b"\xff"[..]) }.read_with(&mut sink).is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn chunks_loudly_success() {
+        assert_eq!(
+            { &(b"abcdefg"[..]) }
+                .chunks_loudly(3)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![b"abc".to_vec(), b"def".to_vec(), b"g".to_vec()]
+        );
+    }
+
+    #[test]
+    fn chunks_silently_success() {
+        assert_eq!(
+            { &(b"abcdef"[..]) }
+                .chunks_silently(3)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![b"abc".to_vec(), b"def".to_vec()]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than zero")]
+    fn chunks_silently_zero_size() {
+        let _ = { &(b"abcdef"[..]) }.chunks_silently(0);
+    }
+
+    #[test]
+    fn lines_loudly_failure() {
+        let mut lines = { &(b"test\n\xff\ntest\n"[..]) }.lines_loudly();
+
+        assert_eq!(lines.next().unwrap().unwrap(), "test");
+        assert!(lines.next().unwrap().is_err());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn lines_loudly_success() {
+        assert_eq!(
+            { &(b"a\r\nb\nc"[..]) }
+                .lines_loudly()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn lines_silently_failure() {
+        let mut lines = { &(b"test\n\xff\ntest\n"[..]) }.lines_silently();
+
+        assert_eq!(lines.next().unwrap().unwrap(), "test");
+        assert!(lines.next().unwrap().is_err());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn lines_silently_success() {
+        assert_eq!(
+            { &(b"a\r\nb\nc"[..]) }
+                .lines_silently()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn read_bytes_loudly_success() {
+        assert_eq!(
+            { &(b"\xff\r\ntest"[..]) }.read_bytes_loudly().unwrap(),
+            b"\xff\r\ntest"
+        );
+    }
+
+    #[test]
+    fn read_bytes_silently_success() {
+        assert_eq!(
+            { &(b"\xff\r\ntest"[..]) }.read_bytes_silently().unwrap(),
+            b"\xff\r\ntest"
+        );
+    }
 
     #[test]
     fn read_loudly_failure() {
@@ -36,6 +128,64 @@ mod buf_read_reader {
         assert_eq!({ &(b"test"[..]) }.read_loudly().unwrap(), "test\n");
     }
 
+    #[test]
+    fn read_normalised_loudly_failure() {
+        assert!({ &(b"\xff"[..]) }
+            .read_normalised_loudly(LineEnding::Preserve)
+            .is_err());
+    }
+
+    #[test]
+    fn read_normalised_loudly_success_crlf() {
+        assert_eq!(
+            { &(b"a\nb\r\nc\rd"[..]) }
+                .read_normalised_loudly(LineEnding::CrLf)
+                .unwrap(),
+            "a\r\nb\r\nc\rd"
+        );
+    }
+
+    #[test]
+    fn read_normalised_silently_failure() {
+        assert!({ &(b"\xff"[..]) }
+            .read_normalised_silently(LineEnding::Preserve)
+            .is_err());
+    }
+
+    #[test]
+    fn read_normalised_silently_success_crlf() {
+        assert_eq!(
+            { &(b"a\nb\r\nc\rd"[..]) }
+                .read_normalised_silently(LineEnding::CrLf)
+                .unwrap(),
+            "a\r\nb\r\nc\rd"
+        );
+    }
+
+    #[test]
+    fn read_normalised_silently_success_lf() {
+        assert_eq!(
+            { &(b"a\nb\r\nc\rd\r\n"[..]) }
+                .read_normalised_silently(LineEnding::Lf)
+                .unwrap(),
+            "a\nb\nc\rd\n"
+        );
+    }
+
+    #[test]
+    fn read_normalised_silently_success_preserve() {
+        let f = "tests/assets/line-endings.txt";
+
+        assert_eq!(
+            std::fs::File::open(f)
+                .map(std::io::BufReader::new)
+                .unwrap()
+                .read_normalised_silently(LineEnding::Preserve)
+                .unwrap(),
+            f.read_silently().unwrap()
+        );
+    }
+
     #[test]
     fn read_silently_failure() {
         assert!("👍"
@@ -56,6 +206,78 @@ mod buf_read_reader {
 mod option_reader {
     use aeruginous_io::OptionReader;
 
+    #[test]
+    fn read_with_failure_some() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(Some("no_such_file.txt")
+            .read_with(&b""[..], &mut sink)
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn chunks_silently_success_none() {
+        assert_eq!(
+            None::<&str>
+                .chunks_silently(&b"test"[..], 2)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![b"te".to_vec(), b"st".to_vec()]
+        );
+    }
+
+    #[test]
+    fn chunks_silently_success_some() {
+        assert_eq!(
+            Some("tests/assets/non-utf-8.bin")
+                .chunks_silently(&b""[..], 4)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![b"\xff\xfe\x00\x80".to_vec(), b"\n".to_vec()]
+        );
+    }
+
+    #[test]
+    fn lines_loudly_success_none() {
+        assert_eq!(
+            None::<&str>
+                .lines_loudly(&b"a\nb\n"[..])
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn lines_silently_success_some() {
+        assert_eq!(
+            Some("tests/assets/line-endings.txt")
+                .lines_silently(&b""[..])
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec!["first", "second\rthird", "last"]
+        );
+    }
+
+    #[test]
+    fn read_bytes_silently_success_none() {
+        assert_eq!(
+            None::<&str>.read_bytes_silently(&b"\xfftest"[..]).unwrap(),
+            b"\xfftest"
+        );
+    }
+
+    #[test]
+    fn read_bytes_silently_success_some() {
+        assert_eq!(
+            Some("tests/assets/non-utf-8.bin")
+                .read_bytes_silently(&b""[..])
+                .unwrap(),
+            b"\xff\xfe\x00\x80\n"
+        );
+    }
+
     #[test]
     fn method_result_equality_none() {
         assert_eq!(
@@ -110,6 +332,108 @@ mod option_reader {
 mod path_buf_like_reader {
     use aeruginous_io::PathBufLikeReader;
 
+    #[test]
+    fn lines_with_failure_file_does_not_exist() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!("no_such_file.txt"
+            .lines_with(&mut sink)
+            .next()
+            .unwrap()
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn chunks_loudly_failure_file_does_not_exist() {
+        let mut chunks = "no_such_file.txt".chunks_loudly(8);
+
+        assert!(chunks.next().unwrap().is_err());
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn chunks_silently_failure_file_does_not_exist() {
+        let mut chunks = "no_such_file.txt".chunks_silently(8);
+
+        assert!(chunks.next().unwrap().is_err());
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn chunks_silently_success() {
+        assert_eq!(
+            "tests/assets/GPL-3.0.rs"
+                .chunks_silently(16)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+                .concat(),
+            "tests/assets/GPL-3.0.rs".read_bytes_silently().unwrap()
+        );
+    }
+
+    #[test]
+    fn lines_loudly_failure_file_does_not_exist() {
+        let mut lines = "no_such_file.txt".lines_loudly();
+
+        assert!(lines.next().unwrap().is_err());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn lines_silently_failure_file_does_not_exist() {
+        let mut lines = "no_such_file.txt".lines_silently();
+
+        assert!(lines.next().unwrap().is_err());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn lines_silently_success() {
+        assert_eq!(
+            "tests/assets/GPL-3.0.rs"
+                .lines_silently()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            "tests/assets/GPL-3.0.rs"
+                .read_silently()
+                .unwrap()
+                .lines()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn read_bytes_loudly_failure_file_does_not_exist() {
+        assert!("no_such_file.txt".read_bytes_loudly().is_err());
+    }
+
+    #[test]
+    fn read_bytes_loudly_success() {
+        assert_eq!(
+            "tests/assets/non-utf-8.bin".read_bytes_loudly().unwrap(),
+            b"\xff\xfe\x00\x80\n"
+        );
+    }
+
+    #[test]
+    fn read_bytes_silently_failure_file_does_not_exist() {
+        assert!("no_such_file.txt".read_bytes_silently().is_err());
+    }
+
+    #[test]
+    fn read_bytes_silently_success() {
+        assert_eq!(
+            "tests/assets/non-utf-8.bin".read_bytes_silently().unwrap(),
+            b"\xff\xfe\x00\x80\n"
+        );
+    }
+
+    #[test]
+    fn read_silently_failure_invalid_utf_8() {
+        assert!("tests/assets/non-utf-8.bin".read_silently().is_err());
+    }
+
     #[test]
     fn method_result_equality() {
         assert_eq!(
@@ -165,6 +489,95 @@ mod path_buf_like_reader {
 mod vector_reader {
     use aeruginous_io::VectorReader;
 
+    #[test]
+    fn chunks_with_failure_non_empty() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(vec!["tests/assets/GPL-3.0.rs", "no_such_file.txt"]
+            .chunks_with(&b""[..], 4096, &mut sink)
+            .any(|c| c.is_err()));
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn chunks_silently_success_non_empty() {
+        assert_eq!(
+            vec!["tests/assets/non-utf-8.bin", "tests/assets/non-utf-8.bin"]
+                .chunks_silently(&b""[..], 3)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![
+                b"\xff\xfe\x00".to_vec(),
+                b"\x80\n\xff".to_vec(),
+                b"\xfe\x00\x80".to_vec(),
+                b"\n".to_vec()
+            ]
+        );
+    }
+
+    #[test]
+    fn lines_loudly_failure_non_empty() {
+        let mut lines =
+            vec!["tests/assets/line-endings.txt", "no_such_file.txt"]
+                .lines_loudly(&b""[..]);
+
+        assert_eq!(lines.next().unwrap().unwrap(), "first");
+        assert_eq!(lines.next().unwrap().unwrap(), "second\rthird");
+        assert!(lines.next().unwrap().is_err());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn lines_silently_success_empty() {
+        assert_eq!(
+            Vec::<&str>::new()
+                .lines_silently(&b"a\nb"[..])
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn lines_silently_success_non_empty() {
+        assert_eq!(
+            vec![
+                "tests/assets/line-endings.txt",
+                "tests/assets/line-endings.txt"
+            ]
+            .lines_silently(&b""[..])
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+            vec![
+                "first",
+                "second\rthird",
+                "lastfirst",
+                "second\rthird",
+                "last"
+            ]
+        );
+    }
+
+    #[test]
+    fn read_bytes_silently_success_empty() {
+        assert_eq!(
+            Vec::<&str>::new()
+                .read_bytes_silently(&b"\xfftest"[..])
+                .unwrap(),
+            b"\xfftest"
+        );
+    }
+
+    #[test]
+    fn read_bytes_silently_success_non_empty() {
+        assert_eq!(
+            vec!["tests/assets/non-utf-8.bin", "tests/assets/non-utf-8.bin"]
+                .read_bytes_silently(&b""[..])
+                .unwrap(),
+            b"\xff\xfe\x00\x80\n\xff\xfe\x00\x80\n"
+        );
+    }
+
     #[test]
     fn method_result_equality_empty() {
         assert_eq!(
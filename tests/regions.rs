@@ -0,0 +1,223 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod region_reader {
+    use aeruginous_io::{Cause, Markers, PathBufLikeTruncation, RegionReader};
+
+    fn reason(content: &str, f: &str) -> (usize, usize, String) {
+        assert!(content.truncate_silently(f).is_ok());
+
+        let e = f.regions_silently(&Markers::new()).unwrap_err();
+        std::fs::remove_file(f).unwrap();
+
+        match e.cause() {
+            Cause::Parse {
+                line,
+                column,
+                reason,
+            } => (*line, *column, reason.clone()),
+            _ => panic!("unexpected cause"),
+        }
+    }
+
+    #[test]
+    fn custom_markers() {
+        let f = "region_reader_custom_markers.txt";
+
+        assert!("# BEGIN x\n# a\n# END x\n".truncate_silently(f).is_ok());
+
+        let regions = f
+            .regions_silently(&Markers::new().start("BEGIN {}").end("END {}"))
+            .unwrap();
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].name(), "x");
+        assert_eq!(regions[0].content(), "a\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn end_without_start() {
+        assert_eq!(
+            reason(
+                "a\n  #[aeruginous::mercy::0001::end]\n",
+                "region_reader_end_without_start.txt"
+            ),
+            (
+                2,
+                3,
+                "end marker of region '0001' without start marker".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn mismatch() {
+        assert_eq!(
+            reason(
+                "#[aeruginous::mercy::0001::start]\n\
+                 #[aeruginous::mercy::0002::end]\n",
+                "region_reader_mismatch.txt"
+            ),
+            (
+                2,
+                1,
+                "end marker of region '0002' closes region '0001'".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn mirrored_readme() {
+        let lib = "src/lib.rs".regions_silently(&Markers::new()).unwrap();
+        let readme = "README.md".regions_silently(&Markers::new()).unwrap();
+
+        assert_eq!(lib[0].name(), "0003");
+        assert_eq!(lib[0].line(), 20);
+        assert_eq!(lib[0].prefix(), "//! ");
+        assert_eq!(readme[0].prefix(), "");
+        assert_eq!(lib[0].content(), readme[0].content());
+    }
+
+    #[test]
+    fn nested() {
+        assert_eq!(
+            reason(
+                "#[aeruginous::mercy::0001::start]\n\
+                 #[aeruginous::mercy::0002::start]\n",
+                "region_reader_nested.txt"
+            ),
+            (
+                2,
+                1,
+                "start marker of region '0002' nested in region '0001'"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn start_without_end() {
+        assert_eq!(
+            reason(
+                "a\nb\n#[aeruginous::mercy::0001::start]\n",
+                "region_reader_start_without_end.txt"
+            ),
+            (
+                3,
+                1,
+                "start marker of region '0001' without end marker".to_string()
+            )
+        );
+    }
+}
+
+mod region_truncation {
+    use aeruginous_io::{
+        Bytes, Markers, PathBufLikeReader, PathBufLikeTruncation,
+        RegionTruncation,
+    };
+
+    #[test]
+    fn missing_region() {
+        let f = "region_truncation_missing_region.md";
+        let original = "README.md".read_silently().unwrap();
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(original.as_str().truncate_silently(f).is_ok());
+        assert!("a"
+            .truncate_region_with(
+                f,
+                "no_such_region",
+                &Markers::new(),
+                &mut sink
+            )
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+        assert_eq!(f.read_silently().unwrap(), original);
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn non_utf_8() {
+        let f = "region_truncation_non_utf_8.md";
+        let original = "README.md".read_silently().unwrap();
+
+        assert!(original.as_str().truncate_silently(f).is_ok());
+
+        let e = Bytes(&[0xff][..])
+            .truncate_region_silently(f, "0003", &Markers::new())
+            .unwrap_err();
+
+        assert_eq!(e.kind(), Some(std::io::ErrorKind::InvalidData));
+        assert_eq!(e.exit_code(), sysexits::ExitCode::DataErr);
+        assert_eq!(f.read_silently().unwrap(), original);
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn preserve_prefix() {
+        let f = "region_truncation_preserve_prefix.txt";
+
+        assert!("x = 1\n# #[aeruginous::mercy::0001::start]\n# old\n\
+                 # #[aeruginous::mercy::0001::end]\ny = 2\n"
+            .truncate_silently(f)
+            .is_ok());
+        assert!("new\n\nlines"
+            .truncate_region_silently(f, "0001", &Markers::new())
+            .is_ok());
+        assert_eq!(
+            f.read_silently().unwrap(),
+            "x = 1\n# #[aeruginous::mercy::0001::start]\n# new\n#\n# lines\n\
+             # #[aeruginous::mercy::0001::end]\ny = 2\n"
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn unchanged_round_trip() {
+        let f = "region_truncation_unchanged_round_trip.rs";
+        let original = "src/lib.rs".read_silently().unwrap();
+
+        assert!(original.as_str().truncate_silently(f).is_ok());
+
+        let content = std::fs::read_to_string("README.md")
+            .unwrap()
+            .split("mercy::0003::start] ------------------->\n")
+            .nth(1)
+            .unwrap()
+            .split("<!--")
+            .next()
+            .unwrap()
+            .to_string();
+
+        assert!(content
+            .truncate_region_loudly(f, "0003", &Markers::new())
+            .is_ok());
+        assert_eq!(f.read_silently().unwrap(), original);
+
+        std::fs::remove_file(f).unwrap();
+    }
+}
+
+/******************************************************************************/
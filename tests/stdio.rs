@@ -0,0 +1,174 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod stdio_path {
+    use aeruginous_io::{
+        Check, Diff, PathBufLikeAppendix, PathBufLikeReader,
+        PathBufLikeTruncation, Policy, StdioPath, VectorReader,
+    };
+
+    #[test]
+    fn check_silently_stdout() {
+        assert_eq!(
+            "test".check_silently(StdioPath::from("-")).unwrap(),
+            Check::WouldModify
+        );
+        assert_eq!(
+            std::fs::read_to_string("tests/assets/GPL-3.0.rs")
+                .unwrap()
+                .check_silently(StdioPath::from("tests/assets/GPL-3.0.rs"))
+                .unwrap(),
+            Check::Unchanged
+        );
+    }
+
+    #[test]
+    fn files() {
+        let f = "stdio_path_files.txt";
+        let path = StdioPath::from(f);
+
+        assert!(!path.is_stdio());
+        assert_eq!(path.path(), std::path::Path::new(f));
+        assert!("a\n".truncate_silently(path.clone()).is_ok());
+        assert!("b\n".append_silently(path.clone()).is_ok());
+        assert_eq!(path.read_silently().unwrap(), "a\nb\n");
+        assert_eq!(f.read_silently().unwrap(), "a\nb\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn is_stdio() {
+        assert!(StdioPath::from("-").is_stdio());
+        assert!(StdioPath::from(String::from("-")).is_stdio());
+        assert!(!StdioPath::from("./-").is_stdio());
+        assert!(!StdioPath::from("--").is_stdio());
+    }
+
+    #[test]
+    fn option_truncation() {
+        let f = "stdio_path_option_truncation.txt";
+        let mut buffer = Vec::new();
+
+        assert!(aeruginous_io::OptionTruncation::truncate_silently(
+            "test",
+            Some(StdioPath::from(f)),
+            &mut buffer
+        )
+        .is_ok());
+        assert!(aeruginous_io::OptionTruncation::truncate_silently(
+            "",
+            Some(StdioPath::from("-")),
+            &mut buffer
+        )
+        .is_ok());
+        assert_eq!(f.read_silently().unwrap(), "test");
+        assert!(buffer.is_empty());
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn truncate_dry_run_silently_stdout() {
+        let diff = "test\n"
+            .truncate_dry_run_silently(StdioPath::from("-"), Diff::new())
+            .unwrap();
+
+        assert!(diff.contains("+test\n"));
+        assert!(!std::path::Path::new("-").exists());
+    }
+
+    #[test]
+    fn vector_reader() {
+        let inputs = vec![
+            StdioPath::from("tests/assets/GPL-3.0.rs"),
+            StdioPath::from("tests/assets/line-endings.txt"),
+        ];
+
+        assert_eq!(
+            inputs
+                .read_silently(std::io::BufReader::new(std::io::stdin()))
+                .unwrap(),
+            "tests/assets/GPL-3.0.rs".read_silently().unwrap()
+                + &"tests/assets/line-endings.txt".read_silently().unwrap()
+        );
+    }
+
+    #[test]
+    fn vector_reader_stdin() {
+        use std::io::Write;
+
+        let inputs = [
+            "tests/assets/GPL-3.0.rs",
+            "-",
+            "tests/assets/line-endings.txt",
+        ];
+
+        if std::env::var_os("STDIO_PATH_VECTOR_READER_STDIN").is_some() {
+            let content = inputs
+                .map(StdioPath::from)
+                .to_vec()
+                .read_silently(std::io::BufReader::new(std::io::stdin()))
+                .unwrap();
+
+            std::io::stdout().write_all(content.as_bytes()).unwrap();
+            return;
+        }
+
+        let mut child =
+            std::process::Command::new(std::env::current_exe().unwrap())
+                .args(["--exact", "stdio_path::vector_reader_stdin"])
+                .env("STDIO_PATH_VECTOR_READER_STDIN", "1")
+                .stdin(std::process::Stdio::piped())
+                .stdout(std::process::Stdio::piped())
+                .spawn()
+                .unwrap();
+
+        child.stdin.take().unwrap().write_all(b"stdin\n").unwrap();
+
+        let output = child.wait_with_output().unwrap();
+
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stdout).contains(
+            &(inputs[0].read_silently().unwrap()
+                + "stdin\n"
+                + &inputs[2].read_silently().unwrap())
+        ));
+    }
+
+    #[test]
+    fn vector_truncation() {
+        let f = "stdio_path_vector_truncation.txt";
+
+        assert!(aeruginous_io::VectorTruncation::truncate_silently(
+            "",
+            &[StdioPath::from(f), StdioPath::from("-")],
+            Vec::new(),
+            Policy::Stop
+        )
+        .unwrap()
+        .is_empty());
+        assert_eq!(f.read_silently().unwrap(), "");
+        assert!(!std::path::Path::new("-").exists());
+
+        std::fs::remove_file(f).unwrap();
+    }
+}
+
+/******************************************************************************/
@@ -0,0 +1,195 @@
+/*********************** GNU General Public License 3.0 ***********************\
+|                                                                              |
+|  Copyright (C) 2024 Kevin Matthes                                            |
+|                                                                              |
+|  This program is free software: you can redistribute it and/or modify        |
+|  it under the terms of the GNU General Public License as published by        |
+|  the Free Software Foundation, either version 3 of the License, or           |
+|  (at your option) any later version.                                         |
+|                                                                              |
+|  This program is distributed in the hope that it will be useful,             |
+|  but WITHOUT ANY WARRANTY; without even the implied warranty of              |
+|  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the               |
+|  GNU General Public License for more details.                                |
+|                                                                              |
+|  You should have received a copy of the GNU General Public License           |
+|  along with this program.  If not, see <https://www.gnu.org/licenses/>.      |
+|                                                                              |
+\******************************************************************************/
+
+mod version {
+    use aeruginous_io::{Bump, Version};
+
+    #[test]
+    fn bump() {
+        let version = Version::new(0, 8, 1);
+
+        assert_eq!(version.bump(Bump::Major), Version::new(1, 0, 0));
+        assert_eq!(version.bump(Bump::Minor), Version::new(0, 9, 0));
+        assert_eq!(version.bump(Bump::Patch), Version::new(0, 8, 2));
+        assert_eq!(version.to_string(), "0.8.1");
+    }
+}
+
+mod version_synchroniser {
+    use aeruginous_io::{
+        Bump, Cause, PathBufLikeReader, Version, VersionSynchroniser,
+    };
+    use std::path::PathBuf;
+
+    const FILES: [&str; 5] = [
+        ".version",
+        "CHANGELOG.ron",
+        "CITATION.cff",
+        "Cargo.toml",
+        "src/lib.rs",
+    ];
+
+    fn prepare(directory: &str) -> Vec<PathBuf> {
+        std::fs::create_dir_all(directory.to_string() + "/src").unwrap();
+
+        FILES
+            .iter()
+            .map(|file| {
+                let copy = PathBuf::from(directory).join(file);
+                std::fs::copy(file, &copy).unwrap();
+                copy
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bump_versions() {
+        let directory = "version_synchroniser_bump_versions";
+        let files = prepare(directory);
+        let before = files
+            .iter()
+            .map(|file| file.read_silently().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            files.bump_versions_silently(Bump::Minor).unwrap(),
+            Version::new(0, 9, 0)
+        );
+
+        for (file, before) in files.iter().zip(before) {
+            let after = file.read_silently().unwrap();
+
+            if file.ends_with("CHANGELOG.ron") {
+                assert_eq!(after, before);
+            } else {
+                assert_ne!(after, before);
+                assert_eq!(after.len(), before.len());
+            }
+        }
+
+        assert_eq!(files[0].read_silently().unwrap().trim(), "0.9.0");
+        assert!(files[3]
+            .read_silently()
+            .unwrap()
+            .contains("rust-version = \"1.98.0\"\nversion = \"0.9.0\"\n"));
+        assert!(files[3]
+            .read_silently()
+            .unwrap()
+            .contains("sysexits = \"0.13.0\""));
+        assert!(files[2]
+            .read_silently()
+            .unwrap()
+            .contains("\nversion: 0.9.0\n"));
+        assert!(files[2]
+            .read_silently()
+            .unwrap()
+            .contains("    version: 0.13.0\n"));
+        assert!(files[4]
+            .read_silently()
+            .unwrap()
+            .contains("VERSION: &str = \"v0.9.0\";"));
+        assert!(files.versions_silently().unwrap().iter().all(
+            |(path, version)| *version
+                == if path.ends_with("CHANGELOG.ron") {
+                    Version::new(0, 8, 1)
+                } else {
+                    Version::new(0, 9, 0)
+                }
+        ));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn inconsistent() {
+        let directory = "version_synchroniser_inconsistent";
+        let files = prepare(directory);
+
+        std::fs::write(&files[0], "0.8.2\n").unwrap();
+
+        let versions = files.versions_silently().unwrap();
+
+        assert_eq!(versions[0], (files[0].clone(), Version::new(0, 8, 2)));
+        assert!(versions[1..]
+            .iter()
+            .all(|(_, version)| *version == Version::new(0, 8, 1)));
+        assert_eq!(
+            files.bump_versions_silently(Bump::Patch).unwrap(),
+            Version::new(0, 8, 3)
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn malformed() {
+        let directory = "version_synchroniser_malformed";
+        let files = prepare(directory);
+
+        std::fs::write(&files[0], "0.8\n").unwrap();
+
+        let e = files
+            .set_versions_silently(Version::new(1, 0, 0))
+            .unwrap_err();
+
+        assert!(matches!(
+            e.cause(),
+            Cause::Parse { line: 1, column: 1, reason }
+                if reason == "malformed version '0.8'"
+        ));
+        assert_eq!(
+            files[4].read_silently().unwrap(),
+            "src/lib.rs".read_silently().unwrap()
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn released() {
+        let directory = "version_synchroniser_released";
+        let files = prepare(directory);
+        let e = files
+            .set_versions_silently(Version::new(0, 8, 0))
+            .unwrap_err();
+
+        assert!(matches!(
+            e.cause(),
+            Cause::Parse { reason, .. }
+                if reason == "released version 0.8.1 exceeds 0.8.0"
+        ));
+        assert_eq!(e.exit_code(), sysexits::ExitCode::DataErr);
+        assert_eq!(
+            files[0].read_silently().unwrap(),
+            ".version".read_silently().unwrap()
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(matches!(
+            vec!["README.md"].versions_silently().unwrap_err().cause(),
+            Cause::Parse { reason, .. } if reason == "unknown file format"
+        ));
+    }
+}
+
+/******************************************************************************/
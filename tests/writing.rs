@@ -20,6 +20,45 @@
 mod path_buf_like_appendix {
     use aeruginous_io::{PathBufLikeAppendix, PathBufLikeReader};
 
+    #[test]
+    fn append_dry_run_silently_success() {
+        assert_eq!(
+            "appended\n"
+                .append_dry_run_silently(
+                    "tests/assets/line-endings.txt",
+                    aeruginous_io::Diff::new().context(0)
+                )
+                .unwrap(),
+            "--- a/tests/assets/line-endings.txt\n\
+             +++ b/tests/assets/line-endings.txt\n\
+             @@ -3 +3 @@\n\
+             -last\n\\ No newline at end of file\n\
+             +lastappended\n"
+        );
+    }
+
+    #[test]
+    fn append_dry_run_with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!("test"
+            .append_dry_run_with(
+                "tests/",
+                aeruginous_io::Diff::new(),
+                &mut sink
+            )
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn append_with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(String::new().append_with("tests/", &mut sink).is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
     #[test]
     fn append_loudly_failure() {
         assert!(String::new().append_loudly("tests/").is_err());
@@ -106,7 +145,195 @@ along with this program.  If not, see <https://www.gnu.org/licenses/>.
 }
 
 mod path_buf_like_truncation {
-    use aeruginous_io::{PathBufLikeReader, PathBufLikeTruncation};
+    use aeruginous_io::{
+        Bytes, Check, PathBufLikeReader, PathBufLikeTruncation,
+    };
+
+    fn leftovers(name: &str) -> usize {
+        std::fs::read_dir(".")
+            .unwrap()
+            .filter(|e| {
+                e.as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&format!(".{name}."))
+            })
+            .count()
+    }
+
+    #[test]
+    fn check_loudly_failure() {
+        assert!(String::new().check_loudly("tests/").is_err());
+    }
+
+    #[test]
+    fn check_silently_states() {
+        let f = "path_buf_like_truncation_check_silently_states.txt";
+
+        assert_eq!("test\n".check_silently(f).unwrap(), Check::WouldCreate);
+        assert!(!std::path::Path::new(f).exists());
+        assert!("test\n".truncate_silently(f).is_ok());
+        assert_eq!("test\n".check_silently(f).unwrap(), Check::Unchanged);
+        assert_eq!("other\n".check_silently(f).unwrap(), Check::WouldModify);
+        assert_eq!(f.read_silently().unwrap(), "test\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn check_with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(String::new().check_with("tests/", &mut sink).is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn truncate_atomically_loudly_success() {
+        let f =
+            "path_buf_like_truncation_truncate_atomically_loudly_success.txt";
+
+        assert!("old\n".truncate_atomically_loudly(f).is_ok());
+        assert!("test\n".truncate_atomically_loudly(f).is_ok());
+        assert_eq!(f.read_silently().unwrap(), "test\n");
+        assert_eq!(leftovers(f), 0);
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn truncate_atomically_missing_directory() {
+        let f = "no_such_directory/file.txt";
+
+        assert!("test\n".truncate_atomically_silently(f).is_err());
+        assert!(!std::path::Path::new("no_such_directory").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn truncate_atomically_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let f = "path_buf_like_truncation_truncate_atomically_permissions.txt";
+
+        assert!("old\n".truncate_silently(f).is_ok());
+        std::fs::set_permissions(f, std::fs::Permissions::from_mode(0o640))
+            .unwrap();
+        assert!("test\n".truncate_atomically_silently(f).is_ok());
+        assert_eq!(
+            std::fs::metadata(f).unwrap().permissions().mode() & 0o777,
+            0o640
+        );
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn truncate_atomically_silently_failure() {
+        assert!(String::new()
+            .truncate_atomically_silently("tests/")
+            .is_err());
+        assert_eq!(leftovers("tests"), 0);
+    }
+
+    #[test]
+    fn truncate_atomically_silently_success() {
+        let f =
+            "path_buf_like_truncation_truncate_atomically_silently_success.txt";
+
+        assert!("test\n".truncate_atomically_silently(f).is_ok());
+        assert_eq!(f.read_silently().unwrap(), "test\n");
+        assert_eq!(leftovers(f), 0);
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn truncate_atomically_with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(String::new()
+            .truncate_atomically_with("tests/", &mut sink)
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn truncate_dry_run_loudly_failure() {
+        assert!(String::new()
+            .truncate_dry_run_loudly("tests/", aeruginous_io::Diff::new())
+            .is_err());
+    }
+
+    #[test]
+    fn truncate_dry_run_silently_success() {
+        let f =
+            "path_buf_like_truncation_truncate_dry_run_silently_success.txt";
+
+        assert!("a\nb\n".truncate_silently(f).is_ok());
+        assert_eq!(
+            "a\nc\n"
+                .truncate_dry_run_silently(f, aeruginous_io::Diff::new())
+                .unwrap(),
+            format!("--- a/{f}\n+++ b/{f}\n@@ -1,2 +1,2 @@\n a\n-b\n+c\n")
+        );
+        assert_eq!(f.read_silently().unwrap(), "a\nb\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn truncate_if_changed_loudly_success() {
+        let f =
+            "path_buf_like_truncation_truncate_if_changed_loudly_success.txt";
+
+        assert!("test\n".truncate_if_changed_loudly(f).unwrap());
+        assert!(!"test\n".truncate_if_changed_loudly(f).unwrap());
+        assert!("changed\n".truncate_if_changed_loudly(f).unwrap());
+        assert_eq!(f.read_silently().unwrap(), "changed\n");
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn truncate_if_changed_preserves_modification_time() {
+        let f = "path_buf_like_truncation_truncate_if_changed_preserves_\
+                 modification_time.txt";
+
+        assert!("test\n".truncate_silently(f).is_ok());
+        let before = std::fs::metadata(f).unwrap().modified().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(!"test\n".truncate_if_changed_silently(f).unwrap());
+        assert_eq!(std::fs::metadata(f).unwrap().modified().unwrap(), before);
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn truncate_if_changed_silently_failure() {
+        assert!(String::new()
+            .truncate_if_changed_silently("tests/")
+            .is_err());
+    }
+
+    #[test]
+    fn truncate_if_changed_with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(String::new()
+            .truncate_if_changed_with("tests/", &mut sink)
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
+    #[test]
+    fn truncate_with_failure() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(String::new().truncate_with("tests/", &mut sink).is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
 
     #[test]
     fn truncate_loudly_failure() {
@@ -128,6 +355,24 @@ mod path_buf_like_truncation {
         assert!(String::new().truncate_silently("tests/").is_err());
     }
 
+    #[test]
+    fn truncate_silently_binary() {
+        let f = "path_buf_like_truncation_truncate_silently_binary.bin";
+        let data = [0x00, 0x9f, 0x92, 0xff];
+
+        assert!(Bytes(data).truncate_silently(f).is_ok());
+        assert_eq!(std::fs::read(f).unwrap(), data);
+        assert_eq!(
+            Bytes(&data[..]).check_silently(f).unwrap(),
+            Check::Unchanged
+        );
+        assert!(Bytes(vec![0xfe]).truncate_atomically_silently(f).is_ok());
+        assert_eq!(std::fs::read(f).unwrap(), [0xfe]);
+        assert!(!Bytes([0xfe]).truncate_if_changed_silently(f).unwrap());
+
+        std::fs::remove_file(f).unwrap();
+    }
+
     #[test]
     fn truncate_silently_success() {
         let f = "path_buf_like_truncation_truncate_silently_success.txt";
@@ -139,9 +384,80 @@ mod path_buf_like_truncation {
     }
 }
 
+mod option_appendix {
+    use aeruginous_io::{BufferSink, OptionAppendix, PathBufLikeReader};
+
+    #[test]
+    fn append_loudly_success_none() {
+        let mut buffer = b"a".to_vec();
+
+        assert!("b"
+            .append_loudly(None::<std::path::PathBuf>, &mut buffer)
+            .is_ok());
+        assert_eq!(buffer, b"ab");
+    }
+
+    #[test]
+    fn append_silently_success_some() {
+        let f = "option_appendix_append_silently_success_some.txt";
+        let mut buffer = Vec::new();
+
+        assert!("a\n".append_silently(Some(f), &mut buffer).is_ok());
+        assert!("b\n".append_silently(Some(f), &mut buffer).is_ok());
+        assert_eq!(f.read_silently().unwrap(), "a\nb\n");
+        assert!(buffer.is_empty());
+
+        std::fs::remove_file(f).unwrap();
+    }
+
+    #[test]
+    fn append_with_failure_some() {
+        let mut sink = BufferSink::new();
+
+        assert!("test"
+            .append_with(Some("tests/"), Vec::new(), &mut sink)
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+}
+
 mod option_truncation {
     use aeruginous_io::{OptionTruncation, PathBufLikeReader};
 
+    #[test]
+    fn check_silently_none() {
+        let mut buffer = Vec::new();
+
+        assert_eq!(
+            "test"
+                .check_silently(None::<std::path::PathBuf>, &mut buffer)
+                .unwrap(),
+            aeruginous_io::Check::WouldModify
+        );
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn check_silently_some() {
+        assert_eq!(
+            std::fs::read_to_string("tests/assets/GPL-3.0.rs")
+                .unwrap()
+                .check_silently(Some("tests/assets/GPL-3.0.rs"), Vec::new())
+                .unwrap(),
+            aeruginous_io::Check::Unchanged
+        );
+    }
+
+    #[test]
+    fn truncate_with_failure_some() {
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!(String::new()
+            .truncate_with(Some("tests/"), Vec::new(), &mut sink)
+            .is_err());
+        assert_eq!(sink.messages().len(), 1);
+    }
+
     #[test]
     fn truncate_loudly_success_none() {
         let mut buffer = Vec::new();
@@ -183,8 +499,378 @@ mod option_truncation {
     }
 }
 
+mod vector_appendix {
+    use aeruginous_io::{
+        BufferSink, PathBufLikeReader, Policy, VectorAppendix,
+    };
+
+    #[test]
+    fn append_loudly_success_empty() {
+        let mut buffer = b"a".to_vec();
+
+        assert!("b"
+            .append_loudly(&Vec::<&str>::new(), &mut buffer, Policy::Stop)
+            .unwrap()
+            .is_empty());
+        assert_eq!(buffer, b"ab");
+    }
+
+    #[test]
+    fn append_silently_success() {
+        let f = [
+            "vector_appendix_append_silently_success_1.txt",
+            "vector_appendix_append_silently_success_2.txt",
+        ];
+        let mut buffer = Vec::new();
+
+        for line in ["a\n", "b\n"] {
+            assert!(line
+                .append_silently(&f, &mut buffer, Policy::Stop)
+                .unwrap()
+                .is_empty());
+        }
+
+        for f in f {
+            assert_eq!(f.read_silently().unwrap(), "a\nb\n");
+            std::fs::remove_file(f).unwrap();
+        }
+
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn append_with_collect() {
+        let f = "vector_appendix_append_with_collect.txt";
+        let mut sink = BufferSink::new();
+        let errors = "test"
+            .append_with(
+                &["tests/", f, "src/"],
+                Vec::new(),
+                Policy::Collect,
+                &mut sink,
+            )
+            .unwrap();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[1].path(), Some(std::path::Path::new("src/")));
+        assert_eq!(sink.messages().len(), 2);
+        assert_eq!(f.read_silently().unwrap(), "test");
+
+        std::fs::remove_file(f).unwrap();
+    }
+}
+
+mod vector_truncation {
+    use aeruginous_io::{
+        BufferSink, Bytes, PathBufLikeReader, Policy, VectorTruncation,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn truncate_silently_empty() {
+        let mut buffer = Vec::new();
+
+        assert!(Bytes([0xff])
+            .truncate_silently(
+                &Vec::<PathBuf>::new(),
+                &mut buffer,
+                Policy::Collect
+            )
+            .unwrap()
+            .is_empty());
+        assert_eq!(buffer, [0xff]);
+    }
+
+    #[test]
+    fn truncate_silently_success() {
+        let f = vec![
+            PathBuf::from("vector_truncation_truncate_silently_success_1.txt"),
+            PathBuf::from("vector_truncation_truncate_silently_success_2.txt"),
+        ];
+
+        std::fs::write(&f[0], "old").unwrap();
+        assert!("new\n"
+            .truncate_silently(&f, Vec::new(), Policy::Stop)
+            .unwrap()
+            .is_empty());
+
+        for f in f {
+            assert_eq!(f.read_silently().unwrap(), "new\n");
+            std::fs::remove_file(f).unwrap();
+        }
+    }
+
+    #[test]
+    fn truncate_with_stop() {
+        let f = "vector_truncation_truncate_with_stop.txt";
+        let mut sink = BufferSink::new();
+        let e = "test"
+            .truncate_with(&["tests/", f], Vec::new(), Policy::Stop, &mut sink)
+            .unwrap_err();
+
+        assert_eq!(e.path(), Some(std::path::Path::new("tests/")));
+        assert_eq!(sink.messages().len(), 1);
+        assert!(!std::path::Path::new(f).exists());
+    }
+}
+
 mod writer {
-    use aeruginous_io::Writer;
+    use aeruginous_io::{Bytes, Cause, Retry, Writer};
+    use std::{
+        collections::VecDeque, fmt::Display, io::ErrorKind, time::Duration,
+    };
+
+    enum Fault {
+        Fail(ErrorKind),
+        Short(usize),
+    }
+
+    #[derive(Default)]
+    struct Faulty {
+        buffer: Vec<u8>,
+        calls: usize,
+        faults: VecDeque<Fault>,
+    }
+
+    impl Faulty {
+        fn new(faults: impl IntoIterator<Item = Fault>) -> Self {
+            Self {
+                faults: faults.into_iter().collect(),
+                ..Self::default()
+            }
+        }
+    }
+
+    impl std::io::Write for Faulty {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+
+            let n = match self.faults.pop_front() {
+                Some(Fault::Fail(kind)) => return Err(kind.into()),
+                Some(Fault::Short(n)) => n.min(buf.len()),
+                None => buf.len(),
+            };
+
+            self.buffer.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct Pieces(usize);
+
+    impl Display for Pieces {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.0 == 0 {
+                return Err(std::fmt::Error);
+            }
+
+            (0..self.0).try_for_each(|_| f.write_str("ab"))
+        }
+    }
+
+    #[test]
+    fn write_silently_binary() {
+        let data = vec![0xff; 10_000];
+        let mut faulty = Faulty::default();
+
+        assert!(Bytes(&data).write_silently(&mut faulty).is_ok());
+        assert_eq!(faulty.buffer, data);
+        assert_eq!(faulty.calls, 1);
+    }
+
+    #[test]
+    fn write_silently_display_failure() {
+        let mut faulty = Faulty::default();
+        let e = Pieces(0).write_silently(&mut faulty).unwrap_err();
+
+        assert!(matches!(e.cause(), Cause::Io(_)));
+        assert_eq!(e.kind(), Some(ErrorKind::Other));
+        assert_eq!(faulty.calls, 0);
+    }
+
+    #[test]
+    fn write_silently_display_streamed() {
+        let mut faulty = Faulty::default();
+
+        assert!(Pieces(10_000).write_silently(&mut faulty).is_ok());
+        assert_eq!(faulty.buffer, "ab".repeat(10_000).as_bytes());
+        assert_eq!(faulty.calls, 3);
+    }
+
+    #[test]
+    fn write_silently_display_incomplete() {
+        let mut faulty = Faulty::new([Fault::Short(8192), Fault::Short(0)]);
+        let e = Pieces(10_000).write_silently(&mut faulty).unwrap_err();
+
+        assert!(matches!(
+            e.cause(),
+            Cause::PartialWrite {
+                expected: None,
+                written: 8192
+            }
+        ));
+        assert_eq!(
+            e.to_string(),
+            "Creating an exact copy in the stream was not possible: only 8192 \
+             bytes were written."
+        );
+    }
+
+    #[test]
+    fn write_silently_bytes_incomplete() {
+        let mut faulty = Faulty::new([Fault::Short(8192), Fault::Short(0)]);
+        let e = Bytes(vec![0; 10_000])
+            .write_silently(&mut faulty)
+            .unwrap_err();
+
+        assert!(matches!(
+            e.cause(),
+            Cause::PartialWrite {
+                expected: Some(10_000),
+                written: 8192
+            }
+        ));
+    }
+
+    #[test]
+    fn write_silently_failure_after_progress() {
+        let mut faulty =
+            Faulty::new([Fault::Short(2), Fault::Fail(ErrorKind::BrokenPipe)]);
+        let e = "test".write_silently(&mut faulty).unwrap_err();
+
+        assert!(matches!(
+            e.cause(),
+            Cause::Incomplete {
+                expected: Some(4),
+                written: 2,
+                ..
+            }
+        ));
+        assert_eq!(e.kind(), Some(ErrorKind::BrokenPipe));
+        assert_eq!(e.exit_code(), sysexits::ExitCode::IoErr);
+        assert!(e
+            .to_string()
+            .starts_with("Writing to the stream failed after 2 of 4 bytes: "));
+        assert_eq!(faulty.buffer, b"te");
+    }
+
+    #[test]
+    fn write_silently_interrupted() {
+        let mut faulty = Faulty::new([
+            Fault::Fail(ErrorKind::Interrupted),
+            Fault::Short(1),
+            Fault::Fail(ErrorKind::Interrupted),
+            Fault::Fail(ErrorKind::Interrupted),
+        ]);
+
+        assert!("test".write_silently(&mut faulty).is_ok());
+        assert_eq!(faulty.buffer, b"test");
+        assert_eq!(faulty.calls, 5);
+    }
+
+    #[test]
+    fn write_silently_short_writes() {
+        let mut faulty = Faulty::new((0..3).map(|_| Fault::Short(1)));
+
+        assert!("test".write_silently(&mut faulty).is_ok());
+        assert_eq!(faulty.buffer, b"test");
+        assert_eq!(faulty.calls, 4);
+    }
+
+    #[test]
+    fn write_silently_zero_progress() {
+        let mut faulty = Faulty::new([Fault::Short(3), Fault::Short(0)]);
+        let e = "test".write_silently(&mut faulty).unwrap_err();
+
+        assert!(matches!(
+            e.cause(),
+            Cause::PartialWrite {
+                expected: Some(4),
+                written: 3
+            }
+        ));
+    }
+
+    #[test]
+    fn write_retrying_silently_exhausted() {
+        let mut faulty =
+            Faulty::new((0..4).map(|_| Fault::Fail(ErrorKind::WouldBlock)));
+        let e = "test"
+            .write_retrying_silently(
+                &mut faulty,
+                Retry::new().attempts(2).delay(Duration::ZERO),
+            )
+            .unwrap_err();
+
+        assert!(matches!(e.cause(), Cause::Io(_)));
+        assert_eq!(e.kind(), Some(ErrorKind::WouldBlock));
+        assert_eq!(faulty.calls, 3);
+        assert!(faulty.buffer.is_empty());
+    }
+
+    #[test]
+    fn write_retrying_silently_would_block() {
+        let mut faulty = Faulty::new([
+            Fault::Short(2),
+            Fault::Fail(ErrorKind::WouldBlock),
+            Fault::Fail(ErrorKind::WouldBlock),
+        ]);
+
+        assert!("test"
+            .write_retrying_silently(
+                &mut faulty,
+                Retry::new().attempts(2).delay(Duration::ZERO)
+            )
+            .is_ok());
+        assert_eq!(faulty.buffer, b"test");
+    }
+
+    #[test]
+    fn write_retrying_silently_without_retries() {
+        let mut faulty = Faulty::new([Fault::Fail(ErrorKind::Interrupted)]);
+
+        assert_eq!(
+            "test"
+                .write_retrying_silently(&mut faulty, Retry::new().attempts(0))
+                .unwrap_err()
+                .kind(),
+            Some(ErrorKind::Interrupted)
+        );
+    }
+
+    #[test]
+    fn write_with_interrupted() {
+        let mut faulty = Faulty::new([
+            Fault::Fail(ErrorKind::Interrupted),
+            Fault::Fail(ErrorKind::Interrupted),
+        ]);
+        let mut logger = aeruginous_io::Logger::new(
+            Vec::new(),
+            aeruginous_io::Level::Warn,
+            aeruginous_io::Format::Plain,
+        );
+
+        assert!("test".write_with(&mut faulty, &mut logger).is_ok());
+        assert_eq!(
+            String::from_utf8(logger.into_inner()).unwrap(),
+            "Warning for the stream: retried 2 interrupted or blocked \
+             writes.\n"
+        );
+    }
+
+    #[test]
+    fn write_with_success() {
+        let mut buffer = Vec::new();
+        let mut sink = aeruginous_io::BufferSink::new();
+
+        assert!("test".write_with(&mut buffer, &mut sink).is_ok());
+        assert_eq!(buffer, b"test");
+        assert!(sink.messages().is_empty());
+    }
 
     #[test]
     fn write_loudly_success() {